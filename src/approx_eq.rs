@@ -0,0 +1,78 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{Color, Mat3, Mat4, Quaternion, Vec2, Vec3, Vec4};
+
+macro_rules! impl_approx {
+    ($t:ident, |$v:ident| $components:expr) => {
+        const _: () = {
+            fn components($v: &$t) -> impl AsRef<[f32]> + '_ {
+                $components
+            }
+
+            fn all(a: &$t, b: &$t, f: impl Fn(&f32, &f32) -> bool) -> bool {
+                let (a, b) = (components(a), components(b));
+                a.as_ref().iter().zip(b.as_ref()).all(|(a, b)| f(a, b))
+            }
+
+            impl AbsDiffEq for $t {
+                type Epsilon = f32;
+
+                fn default_epsilon() -> f32 {
+                    f32::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                    all(self, other, |a, b| a.abs_diff_eq(b, epsilon))
+                }
+            }
+
+            impl RelativeEq for $t {
+                fn default_max_relative() -> f32 {
+                    f32::default_max_relative()
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                    all(self, other, |a, b| a.relative_eq(b, epsilon, max_relative))
+                }
+            }
+
+            impl UlpsEq for $t {
+                fn default_max_ulps() -> u32 {
+                    f32::default_max_ulps()
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                    all(self, other, |a, b| a.ulps_eq(b, epsilon, max_ulps))
+                }
+            }
+        };
+    };
+}
+
+impl_approx!(Vec2, |v| [v.x, v.y]);
+impl_approx!(Vec3, |v| [v.x, v.y, v.z]);
+impl_approx!(Vec4, |v| [v.x, v.y, v.z, v.w]);
+impl_approx!(Quaternion, |v| [v.x, v.y, v.z, v.w]);
+impl_approx!(Color, |v| [v.r, v.g, v.b, v.a]);
+impl_approx!(Mat3, |v| v.as_slice());
+impl_approx!(Mat4, |v| v.as_slice());
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+
+    use crate::{Mat4, Quaternion, Vec3};
+
+    #[test]
+    fn approx() {
+        let a = Vec3::new(0.1, 0.2, 0.3) * 3.0;
+        let b = Vec3::new(0.3, 0.6, 0.9);
+        assert_ne!(a, b);
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+        assert_relative_ne!(a, b * 1.01);
+
+        let m = Mat4::rotate(Quaternion::axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.3));
+        assert_relative_eq!(m * m.inverse(), Mat4::IDENTITY, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,278 @@
+use std::fmt::Display;
+
+use crate::{Lerp, Vec3, Vec4};
+
+/// A linear RGBA color with unbounded (HDR) channels
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { r, g, b, a } = self;
+        write!(f, "({r}, {g}, {b}, {a})")
+    }
+}
+
+impl Color {
+    /// Opaque black (0, 0, 0, 1)
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    /// Opaque white (1, 1, 1, 1)
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates an opaque color
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    /// Returns the components of `self` as an array, in the order r, g, b, a
+    pub const fn to_array(&self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Approximates the color of light with the given wavelength in nanometers.
+    ///
+    /// Uses Dan Bruton's piecewise linear approximation of the visible spectrum,
+    /// with the intensity falling off towards its ends.
+    /// Wavelengths outside of \[380; 780\] result in black.
+    pub fn from_wavelength(nm: f32) -> Self {
+        let (r, g, b) = if (380.0..440.0).contains(&nm) {
+            ((440.0 - nm) / (440.0 - 380.0), 0.0, 1.0)
+        } else if (440.0..490.0).contains(&nm) {
+            (0.0, (nm - 440.0) / (490.0 - 440.0), 1.0)
+        } else if (490.0..510.0).contains(&nm) {
+            (0.0, 1.0, (510.0 - nm) / (510.0 - 490.0))
+        } else if (510.0..580.0).contains(&nm) {
+            ((nm - 510.0) / (580.0 - 510.0), 1.0, 0.0)
+        } else if (580.0..645.0).contains(&nm) {
+            (1.0, (645.0 - nm) / (645.0 - 580.0), 0.0)
+        } else if (645.0..=780.0).contains(&nm) {
+            (1.0, 0.0, 0.0)
+        } else {
+            (0.0, 0.0, 0.0)
+        };
+
+        let intensity = if (380.0..420.0).contains(&nm) {
+            0.3 + 0.7 * (nm - 380.0) / (420.0 - 380.0)
+        } else if (420.0..=700.0).contains(&nm) {
+            1.0
+        } else if (700.0..=780.0).contains(&nm) {
+            0.3 + 0.7 * (780.0 - nm) / (780.0 - 700.0)
+        } else {
+            0.0
+        };
+
+        Self::rgb(r * intensity, g * intensity, b * intensity)
+    }
+
+    /// Returns a copy of `self` with `f` applied to the r, g and b channels.
+    ///
+    /// Alpha is left untouched.
+    #[must_use]
+    pub fn map_rgb(&self, f: impl Fn(f32) -> f32) -> Self {
+        Self {
+            r: f(self.r),
+            g: f(self.g),
+            b: f(self.b),
+            a: self.a,
+        }
+    }
+
+    /// Returns `self` with the r, g and b channels raised to the power of `n`, e.g. for gamma curves.
+    ///
+    /// Alpha is left untouched.
+    #[must_use]
+    pub fn powf(&self, n: f32) -> Self {
+        self.map_rgb(|c| c.powf(n))
+    }
+
+    /// Returns `self` with the square root of the r, g and b channels.
+    ///
+    /// Alpha is left untouched.
+    #[must_use]
+    pub fn sqrt(&self) -> Self {
+        self.map_rgb(f32::sqrt)
+    }
+
+    /// Returns `self` with `e` raised to the power of the r, g and b channels.
+    ///
+    /// Alpha is left untouched.
+    #[must_use]
+    pub fn exp(&self) -> Self {
+        self.map_rgb(f32::exp)
+    }
+
+    /// Returns `self` with the natural logarithm of the r, g and b channels.
+    ///
+    /// Alpha is left untouched.
+    #[must_use]
+    pub fn ln(&self) -> Self {
+        self.map_rgb(f32::ln)
+    }
+
+    /// Returns a copy of `self` scaled by `2^ev`, i.e. with an exposure adjustment of `ev` stops
+    #[must_use]
+    pub fn exposed(&self, ev: f32) -> Self {
+        let scale = ev.exp2();
+        self.map_rgb(|c| c * scale)
+    }
+
+    /// Maps `self` from HDR to \[0; 1\] using the Reinhard operator `c / (1 + c)`
+    #[must_use]
+    pub fn tonemap_reinhard(&self) -> Self {
+        self.map_rgb(|c| c / (1.0 + c))
+    }
+
+    /// Maps `self` from HDR to \[0; 1\] using the extended Reinhard operator,
+    /// which maps `white` (and everything brighter) to 1
+    #[must_use]
+    pub fn tonemap_reinhard_extended(&self, white: f32) -> Self {
+        let w2 = white * white;
+        self.map_rgb(|c| (c * (1.0 + c / w2) / (1.0 + c)).min(1.0))
+    }
+
+    /// Maps `self` from HDR to \[0; 1\] using Krzysztof Narkowicz's
+    /// fit of the ACES filmic curve
+    #[must_use]
+    pub fn tonemap_aces_approx(&self) -> Self {
+        const A: f32 = 2.51;
+        const B: f32 = 0.03;
+        const C: f32 = 2.43;
+        const D: f32 = 0.59;
+        const E: f32 = 0.14;
+
+        self.map_rgb(|c| {
+            let c = c * 0.6;
+            ((c * (A * c + B)) / (c * (C * c + D) + E)).clamp(0.0, 1.0)
+        })
+    }
+
+    /// Maps `self` from HDR to \[0; 1\] with the exponential exposure curve `1 - e^(-c * exposure)`
+    #[must_use]
+    pub fn tonemap_exposure(&self, exposure: f32) -> Self {
+        self.map_rgb(|c| 1.0 - (-c * exposure).exp())
+    }
+}
+
+impl Lerp for Color {
+    fn lerp_unclamped(&self, b: Self, t: f32) -> Self {
+        Self {
+            r: self.r + (b.r - self.r) * t,
+            g: self.g + (b.g - self.g) * t,
+            b: self.b + (b.b - self.b) * t,
+            a: self.a + (b.a - self.a) * t,
+        }
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(d: [f32; 4]) -> Self {
+        Self {
+            r: d[0],
+            g: d[1],
+            b: d[2],
+            a: d[3],
+        }
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(v: Color) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Color {
+    fn from((r, g, b, a): (f32, f32, f32, f32)) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+impl From<Color> for (f32, f32, f32, f32) {
+    fn from(v: Color) -> Self {
+        (v.r, v.g, v.b, v.a)
+    }
+}
+
+impl From<Vec4> for Color {
+    fn from(v: Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Color> for Vec4 {
+    fn from(c: Color) -> Self {
+        Vec4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+/// Creates an opaque color
+impl From<Vec3> for Color {
+    fn from(v: Vec3) -> Self {
+        Self::rgb(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tonemap() {
+        let c = Color::new(0.0, 1.0, 3.0, 0.5);
+
+        assert_eq!(c.tonemap_reinhard(), Color::new(0.0, 0.5, 0.75, 0.5));
+        assert_eq!(c.tonemap_reinhard_extended(3.0).b, 1.0);
+        assert_eq!(c.exposed(1.0), Color::new(0.0, 2.0, 6.0, 0.5));
+
+        let aces = Color::rgb(0.0, 0.18, 100.0).tonemap_aces_approx();
+        assert_eq!(aces.r, 0.0);
+        assert!(aces.g > 0.0 && aces.g < 0.5);
+        assert_eq!(aces.b, 1.0);
+
+        let e = c.tonemap_exposure(1.0);
+        assert_eq!(e.r, 0.0);
+        assert!((e.g - (1.0 - (-1.0f32).exp())).abs() < 1e-6);
+    }
+
+    #[test]
+    fn wavelength() {
+        assert_eq!(Color::from_wavelength(300.0), Color::BLACK);
+        assert_eq!(Color::from_wavelength(900.0), Color::BLACK);
+        assert_eq!(Color::from_wavelength(440.0), Color::rgb(0.0, 0.0, 1.0));
+        assert_eq!(Color::from_wavelength(510.0), Color::rgb(0.0, 1.0, 0.0));
+        assert_eq!(Color::from_wavelength(650.0), Color::rgb(1.0, 0.0, 0.0));
+        assert_eq!(Color::from_wavelength(580.0), Color::rgb(1.0, 1.0, 0.0));
+        assert_eq!(Color::from_wavelength(780.0), Color::rgb(0.3, 0.0, 0.0));
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let c = Color::from((0.1, 0.2, 0.3, 1.0));
+        assert_eq!(c, Color::new(0.1, 0.2, 0.3, 1.0));
+        let (r, g, b, a) = c.into();
+        assert_eq!([r, g, b, a], [0.1, 0.2, 0.3, 1.0]);
+    }
+
+    #[test]
+    fn powf() {
+        let c = Color::new(0.25, 1.0, 4.0, 0.5);
+        assert_eq!(c.powf(2.0), Color::new(0.0625, 1.0, 16.0, 0.5));
+        assert_eq!(c.sqrt(), Color::new(0.5, 1.0, 2.0, 0.5));
+        assert_eq!(
+            Color::new(0.0, 0.0, 0.0, 0.5).exp(),
+            Color::new(1.0, 1.0, 1.0, 0.5)
+        );
+        assert_eq!(Color::WHITE.ln(), Color::new(0.0, 0.0, 0.0, 1.0));
+    }
+}
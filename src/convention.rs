@@ -0,0 +1,163 @@
+use crate::{Mat4, Quaternion, Vec3, Vec4};
+
+/// The clip space conventions of the different graphics APIs.
+///
+/// All of them use left-handed projection matrices looking along +z,
+/// they only differ in the depth range and the direction of the y axis
+/// in normalized device coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NdcConvention {
+    /// z in \[-1; 1\], y pointing up
+    OpenGl,
+    /// z in \[0; 1\], y pointing down
+    Vulkan,
+    /// z in \[0; 1\], y pointing up
+    DirectX,
+    /// z in \[0; 1\], y pointing up
+    Metal,
+    /// z in \[0; 1\], y pointing up
+    WebGpu,
+}
+
+impl NdcConvention {
+    /// Returns whether z is mapped to \[0; 1\] instead of \[-1; 1\]
+    pub const fn depth_zero_to_one(self) -> bool {
+        !matches!(self, Self::OpenGl)
+    }
+
+    /// Returns whether the y axis points down in normalized device coordinates
+    pub const fn y_down(self) -> bool {
+        matches!(self, Self::Vulkan)
+    }
+}
+
+/// A signed coordinate axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+
+impl Axis {
+    /// Returns the unit vector pointing along `self`
+    pub const fn vector(self) -> Vec3 {
+        match self {
+            Self::PosX => Vec3::X,
+            Self::NegX => Vec3::NEG_X,
+            Self::PosY => Vec3::Y,
+            Self::NegY => Vec3::NEG_Y,
+            Self::PosZ => Vec3::Z,
+            Self::NegZ => Vec3::NEG_Z,
+        }
+    }
+}
+
+/// Describes a 3D coordinate system by the axes that point right, up and forward
+/// from the point of view of a camera, i.e. forward is the default viewing direction.
+///
+/// The axes have to be distinct, e.g. `right` and `up` must not both be along x.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoordinateSystem {
+    pub right: Axis,
+    pub up: Axis,
+    pub forward: Axis,
+}
+
+impl CoordinateSystem {
+    /// The convention used by this crate, e.g. by [`Mat4::look_at_lh()`] and [`Quaternion::forward()`]:
+    /// left-handed with +x right, +y up and +z forward. Also used by Unity.
+    pub const GFX_MATHS: Self = Self::new(Axis::PosX, Axis::PosY, Axis::PosZ);
+    /// Right-handed with +y up and -z forward, as used by OpenGL, glTF and Maya
+    pub const OPENGL: Self = Self::new(Axis::PosX, Axis::PosY, Axis::NegZ);
+    /// Right-handed with +z up and +y forward (the front view looks along +y), as used by Blender
+    pub const BLENDER: Self = Self::new(Axis::PosX, Axis::PosZ, Axis::PosY);
+    /// Left-handed with +z up, +x forward and +y right, as used by Unreal Engine
+    pub const UNREAL: Self = Self::new(Axis::PosY, Axis::PosZ, Axis::PosX);
+
+    pub const fn new(right: Axis, up: Axis, forward: Axis) -> Self {
+        Self { right, up, forward }
+    }
+
+    /// Returns whether `self` is left-handed, i.e. whether `right` x `up` = `forward`
+    pub fn is_left_handed(&self) -> bool {
+        self.right
+            .vector()
+            .cross(self.up.vector())
+            .dot(self.forward.vector())
+            > 0.0
+    }
+
+    /// Returns the matrix whose columns are the right, up and forward axes of `self`
+    fn basis(&self) -> Mat4 {
+        let axis = |a: Axis| {
+            let v = a.vector();
+            Vec4::new(v.x, v.y, v.z, 0.0)
+        };
+
+        Mat4::from_cols(
+            axis(self.right),
+            axis(self.up),
+            axis(self.forward),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+}
+
+impl Mat4 {
+    /// Creates the matrix that converts positions and directions given in the coordinate system `from`
+    /// to the coordinate system `to`.
+    ///
+    /// Transformation matrices `m` are converted by `c * m * c.inverse()`, where `c` is this matrix.
+    /// If the handedness of `from` and `to` differs, the matrix mirrors, so the winding order
+    /// of converted triangles has to be flipped.
+    pub fn change_of_basis(from: CoordinateSystem, to: CoordinateSystem) -> Self {
+        // the basis matrices are signed permutations, so their inverse is their transpose
+        to.basis() * from.basis().transposed()
+    }
+}
+
+impl Quaternion {
+    /// Converts the rotation `self` given in the coordinate system `from` to the coordinate system `to`,
+    /// see [`Mat4::change_of_basis()`]
+    pub fn change_of_basis(&self, from: CoordinateSystem, to: CoordinateSystem) -> Self {
+        let c = Mat4::change_of_basis(from, to);
+        Self::from_mat4(&(c * Mat4::rotate(*self) * c.transposed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_of_basis() {
+        assert!(CoordinateSystem::GFX_MATHS.is_left_handed());
+        assert!(CoordinateSystem::UNREAL.is_left_handed());
+        assert!(!CoordinateSystem::OPENGL.is_left_handed());
+        assert!(!CoordinateSystem::BLENDER.is_left_handed());
+
+        // y up to z up
+        let c = Mat4::change_of_basis(CoordinateSystem::OPENGL, CoordinateSystem::BLENDER);
+        assert_eq!(c * Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(c * Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, -1.0, 0.0));
+        assert_eq!(c * Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+
+        let back = Mat4::change_of_basis(CoordinateSystem::BLENDER, CoordinateSystem::OPENGL);
+        assert_eq!(back * c, Mat4::IDENTITY);
+
+        // a rotation around "up" stays a rotation around "up"
+        let q = Quaternion::axis_angle(Vec3::new(0.0, 1.0, 0.0), 0.5);
+        let res = q.change_of_basis(CoordinateSystem::GFX_MATHS, CoordinateSystem::UNREAL);
+        let expected = Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 0.5);
+        let d = Vec3::new(res.x - expected.x, res.y - expected.y, res.z - expected.z).magnitude()
+            + (res.w - expected.w).abs();
+        assert!(d < 1e-5, "{res} != {expected}");
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt::Display;
+
+use crate::Quaternion;
+
+use auto_ops::impl_op_ex;
+
+/// A double precision variant of [`Quaternion`].
+///
+/// Useful where f32 precision is not enough, e.g. when integrating rotations over long periods of time.
+/// As there are no double precision vector types yet, vectors are represented as `[f64; 3]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct DQuaternion {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Default for DQuaternion {
+    /// Creates an identity rotation
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Display for DQuaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { x, y, z, w } = self;
+        write!(f, "({x}, {y}, {z}, {w})")
+    }
+}
+
+impl DQuaternion {
+    /// The identity quaternion (represents no rotation)
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Returns `true` if all components of `self` are neither infinite nor NaN
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Creates a rotation of `radians` radians around `axis`, see [`Quaternion::axis_angle()`]
+    pub fn axis_angle(axis: [f64; 3], radians: f64) -> Self {
+        let [x, y, z] = axis;
+        let s = (radians * 0.5).sin() / (x * x + y * y + z * z).sqrt();
+
+        Self {
+            x: x * s,
+            y: y * s,
+            z: z * s,
+            w: (radians * 0.5).cos(),
+        }
+    }
+
+    /// Returns the 4D dot product of `self` and `b`
+    fn dot(&self, b: DQuaternion) -> f64 {
+        self.x * b.x + self.y * b.y + self.z * b.z + self.w * b.w
+    }
+
+    /// Returns whether the magnitude of `self` is within `epsilon` of 1
+    pub fn is_normalized(&self, epsilon: f64) -> bool {
+        (self.dot(*self).sqrt() - 1.0).abs() <= epsilon
+    }
+
+    /// Returns a normalized copy of `self`.
+    ///
+    /// Renormalizing regularly keeps rounding errors from accumulating when multiplying many rotations.
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        let m = self.dot(*self).sqrt();
+        Self::new(self.x / m, self.y / m, self.z / m, self.w / m)
+    }
+
+    /// Returns the conjugate of `self`, which is the inverse rotation for unit quaternions
+    pub const fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the multiplicative inverse of `self`, which does not need to be normalized
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let sqr_magnitude = self.dot(*self);
+        let c = self.conjugate();
+        Self::new(
+            c.x / sqr_magnitude,
+            c.y / sqr_magnitude,
+            c.z / sqr_magnitude,
+            c.w / sqr_magnitude,
+        )
+    }
+
+    /// Spherically interpolates between `a` and `b`, taking the shortest path.
+    ///
+    /// Behaves exactly like [`Quaternion::slerp()`], including its fallback to normalized
+    /// linear interpolation for nearly identical rotations.
+    pub fn slerp(a: DQuaternion, b: DQuaternion, t: f64) -> DQuaternion {
+        let dot = a.dot(b);
+        let cos = dot.abs();
+        let tb_sign = dot.signum();
+
+        // sin(theta) approaches zero for nearly identical rotations,
+        // nlerp is indistinguishable from slerp there
+        if cos > 0.9995 {
+            let (ta, tb) = (1.0 - t, t * tb_sign);
+            return DQuaternion {
+                x: a.x * ta + b.x * tb,
+                y: a.y * ta + b.y * tb,
+                z: a.z * ta + b.z * tb,
+                w: a.w * ta + b.w * tb,
+            }
+            .normalized();
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let ta = ((1.0 - t) * theta).sin() / sin;
+        let tb = (t * theta).sin() / sin * tb_sign;
+
+        DQuaternion {
+            x: a.x * ta + b.x * tb,
+            y: a.y * ta + b.y * tb,
+            z: a.z * ta + b.z * tb,
+            w: a.w * ta + b.w * tb,
+        }
+    }
+
+    /// Returns `v` rotated by `self`
+    pub fn rotate(&self, v: [f64; 3]) -> [f64; 3] {
+        // v + 2w(q x v) + 2(q x (q x v))
+        let q = [self.x, self.y, self.z];
+        let cross = |a: [f64; 3], b: [f64; 3]| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+
+        let t = cross(q, v).map(|c| c * 2.0);
+        let u = cross(q, t);
+        [
+            v[0] + self.w * t[0] + u[0],
+            v[1] + self.w * t[1] + u[1],
+            v[2] + self.w * t[2] + u[2],
+        ]
+    }
+}
+
+impl_op_ex!(*|a: &DQuaternion, b: &DQuaternion| -> DQuaternion {
+    DQuaternion {
+        x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        y: a.w * b.y + a.y * b.w + a.z * b.x - a.x * b.z,
+        z: a.w * b.z + a.z * b.w + a.x * b.y - a.y * b.x,
+        w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    }
+});
+
+impl_op_ex!(-|a: &DQuaternion| -> DQuaternion { a.conjugate() });
+
+impl From<Quaternion> for DQuaternion {
+    fn from(q: Quaternion) -> Self {
+        Self::new(q.x as f64, q.y as f64, q.z as f64, q.w as f64)
+    }
+}
+
+/// Converts to single precision, losing precision
+impl From<DQuaternion> for Quaternion {
+    fn from(q: DQuaternion) -> Self {
+        Self::new(q.x as f32, q.y as f32, q.z as f32, q.w as f32)
+    }
+}
+
+impl From<[f64; 4]> for DQuaternion {
+    fn from(d: [f64; 4]) -> Self {
+        Self::new(d[0], d[1], d[2], d[3])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec3;
+
+    #[test]
+    fn matches_quaternion() {
+        let q = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+        let d = DQuaternion::axis_angle([1.0, 2.0, 3.0], 0.7);
+        let back = Quaternion::from(d);
+        assert!((back.x - q.x).abs() + (back.y - q.y).abs() + (back.w - q.w).abs() < 1e-6);
+
+        let v = d.rotate([1.0, 0.0, 0.0]);
+        let expected = q * Vec3::new(1.0, 0.0, 0.0);
+        assert!((v[0] as f32 - expected.x).abs() < 1e-6);
+        assert!((v[1] as f32 - expected.y).abs() < 1e-6);
+        assert!((v[2] as f32 - expected.z).abs() < 1e-6);
+
+        let id = d * d.inverse();
+        assert!(id.is_normalized(1e-12) && (id.w - 1.0).abs() < 1e-12);
+
+        // the tolerance applies to the magnitude, not the squared magnitude
+        let scaled = DQuaternion::new(0.0, 0.0, 0.0, 1.015);
+        assert!(scaled.is_normalized(0.02) && !scaled.is_normalized(0.01));
+    }
+
+    #[test]
+    fn precision() {
+        // a million tiny steps accumulate to a full turn without losing the axis
+        let step = DQuaternion::axis_angle([0.0, 0.0, 1.0], std::f64::consts::TAU / 1_000_000.0);
+        let mut q = DQuaternion::IDENTITY;
+        for _ in 0..1_000_000 {
+            q = q * step;
+        }
+        let v = q.rotate([1.0, 0.0, 0.0]);
+        assert!((v[0] - 1.0).abs() < 1e-8 && v[1].abs() < 1e-8);
+
+        let half = DQuaternion::slerp(
+            DQuaternion::IDENTITY,
+            DQuaternion::axis_angle([0.0, 0.0, 1.0], 2.0),
+            0.5,
+        );
+        assert!((half.w - 0.5f64.cos()).abs() < 1e-12);
+
+        // same results as Quaternion::slerp(), including the nlerp fallback for close rotations
+        for angle in [2.0, 0.01] {
+            let a = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.3);
+            let b = a * Quaternion::axis_angle(Vec3::new(0.0, 1.0, 0.0), angle);
+            let single = Quaternion::slerp(a, b, 0.3);
+            let double = Quaternion::from(DQuaternion::slerp(a.into(), b.into(), 0.3));
+            assert!((single.x - double.x).abs() + (single.w - double.w).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::InnerSpace;
+
+/// A cubic bezier timing function from (0, 0) to (1, 1),
+/// as used by CSS' `cubic-bezier(x1, y1, x2, y2)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezierEasing {
+    ax: f32,
+    bx: f32,
+    cx: f32,
+    ay: f32,
+    by: f32,
+    cy: f32,
+}
+
+impl CubicBezierEasing {
+    /// CSS' `linear`
+    pub const LINEAR: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+    /// CSS' `ease`
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    /// CSS' `ease-in`
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    /// CSS' `ease-out`
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    /// CSS' `ease-in-out`
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// Creates a timing function with the control points (`x1`, `y1`) and (`x2`, `y2`).
+    ///
+    /// As in CSS, `x1` and `x2` have to be within \[0; 1\], so that the curve is a function of x.
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        let cx = 3.0 * x1;
+        let bx = 3.0 * (x2 - x1) - cx;
+        let cy = 3.0 * y1;
+        let by = 3.0 * (y2 - y1) - cy;
+
+        Self {
+            ax: 1.0 - cx - bx,
+            bx,
+            cx,
+            ay: 1.0 - cy - by,
+            by,
+            cy,
+        }
+    }
+
+    fn sample_x(&self, t: f32) -> f32 {
+        ((self.ax * t + self.bx) * t + self.cx) * t
+    }
+
+    fn sample_y(&self, t: f32) -> f32 {
+        ((self.ay * t + self.by) * t + self.cy) * t
+    }
+
+    fn sample_dx(&self, t: f32) -> f32 {
+        (3.0 * self.ax * t + 2.0 * self.bx) * t + self.cx
+    }
+
+    /// Returns the curve parameter at which the curve reaches `x`
+    fn solve_t(&self, x: f32) -> f32 {
+        const EPSILON: f32 = 1e-6;
+
+        // Newton's method converges quickly for most curves
+        let mut t = x;
+        for _ in 0..8 {
+            let err = self.sample_x(t) - x;
+            if err.abs() < EPSILON {
+                return t;
+            }
+
+            let dx = self.sample_dx(t);
+            if dx.abs() < EPSILON {
+                break;
+            }
+            t -= err / dx;
+        }
+
+        // fall back to bisection, which always converges since x(t) is monotonic
+        let mut lo = 0.0;
+        let mut hi = 1.0;
+        t = x;
+        while hi - lo > EPSILON {
+            let err = self.sample_x(t) - x;
+            if err.abs() < EPSILON {
+                break;
+            }
+
+            if err > 0.0 {
+                hi = t;
+            } else {
+                lo = t;
+            }
+            t = (lo + hi) * 0.5;
+        }
+
+        t
+    }
+
+    /// Returns the eased progress for the linear progress `x`.
+    ///
+    /// `x` is clamped to \[0; 1\].
+    pub fn evaluate(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        self.sample_y(self.solve_t(x))
+    }
+}
+
+/// Moves `current` towards `target` like a critically damped spring, without overshooting it.
+///
+/// `velocity` holds the current rate of change and has to be kept between calls, starting at zero.
+/// `smooth_time` is roughly the time it takes to reach the target, `dt` is the time step.
+/// Frame rate independent, so it can be used for camera follow or UI motion.
+pub fn smooth_damp<V: InnerSpace>(
+    current: V,
+    target: V,
+    velocity: &mut V,
+    smooth_time: f32,
+    dt: f32,
+) -> V {
+    let omega = 2.0 / smooth_time.max(1e-4);
+
+    // approximation of exp(-omega * dt)
+    let x = omega * dt;
+    let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+    let change = current - target;
+    let temp = (*velocity + change * omega) * dt;
+    *velocity = (*velocity - temp * omega) * exp;
+    let result = target + (change + temp) * exp;
+
+    // prevent overshooting the target
+    if (target - current).dot(result - target) > 0.0 {
+        *velocity = V::ZERO;
+        target
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3};
+
+    #[test]
+    fn evaluate() {
+        for i in 0..=10 {
+            let x = i as f32 / 10.0;
+            assert!((CubicBezierEasing::LINEAR.evaluate(x) - x).abs() < 1e-5);
+        }
+
+        assert_eq!(CubicBezierEasing::EASE.evaluate(0.0), 0.0);
+        assert!((CubicBezierEasing::EASE.evaluate(1.0) - 1.0).abs() < 1e-5);
+        assert!((CubicBezierEasing::EASE.evaluate(0.5) - 0.8024).abs() < 1e-3);
+        assert!((CubicBezierEasing::EASE_IN_OUT.evaluate(0.5) - 0.5).abs() < 1e-5);
+        assert!(CubicBezierEasing::EASE_IN.evaluate(0.25) < 0.25);
+        assert!(CubicBezierEasing::EASE_OUT.evaluate(0.25) > 0.25);
+
+        // steep curve with a vertical tangent, where Newton's method stalls
+        let steep = CubicBezierEasing::new(0.0, 1.0, 0.0, 1.0);
+        assert!(steep.evaluate(0.001) > 0.1);
+        assert!((steep.evaluate(2.0) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn smooth_damp() {
+        let mut pos = 0.0f32;
+        let mut vel = 0.0;
+        let mut last = pos;
+        for _ in 0..60 {
+            pos = super::smooth_damp(pos, 10.0, &mut vel, 0.25, 1.0 / 60.0);
+            assert!(pos >= last && pos <= 10.0);
+            last = pos;
+        }
+        assert!((pos - 10.0).abs() < 0.1);
+
+        // a single large step ends up at the target instead of overshooting
+        let mut vel = Vec3::ZERO;
+        let target = Vec3::new(1.0, 2.0, 3.0);
+        let pos = super::smooth_damp(Vec3::ZERO, target, &mut vel, 0.1, 10.0);
+        assert!((pos - target).magnitude() < 1e-3);
+
+        let mut vel = Vec2::new(0.0, 100.0);
+        let pos = super::smooth_damp(Vec2::ZERO, Vec2::new(0.0, 1.0), &mut vel, 1.0, 0.5);
+        assert_eq!(pos, Vec2::new(0.0, 1.0));
+        assert_eq!(vel, Vec2::ZERO);
+    }
+}
@@ -0,0 +1,87 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{Vec2, Vec3, Vec4};
+
+/// A wrapper that compares and hashes a vector by the bit patterns of its components,
+/// so that it can be used as a key in a `HashMap` or `HashSet`, e.g. for welding mesh vertices.
+///
+/// Equality is bit-exact: `0.0` and `-0.0` are different keys,
+/// while NaNs with the same bit pattern are equal.
+/// Snap values to a grid first to merge nearly equal vectors,
+/// see [`Vec3::snap_to_grid()`].
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Hashable<T>(pub T);
+
+macro_rules! impl_hashable {
+    ($t:ident) => {
+        impl PartialEq for Hashable<$t> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for Hashable<$t> {}
+
+        impl Hash for Hashable<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl From<$t> for Hashable<$t> {
+            fn from(v: $t) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<Hashable<$t>> for $t {
+            fn from(v: Hashable<$t>) -> Self {
+                v.0
+            }
+        }
+    };
+}
+
+impl_hashable!(Vec2);
+impl_hashable!(Vec3);
+impl_hashable!(Vec4);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn weld() {
+        let vertices = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+        ];
+
+        let mut unique = HashMap::new();
+        let indices: Vec<usize> = vertices
+            .iter()
+            .map(|&v| {
+                let next = unique.len();
+                *unique.entry(Hashable(v)).or_insert(next)
+            })
+            .collect();
+
+        assert_eq!(unique.len(), 3);
+        assert_eq!(indices, [0, 1, 0, 1, 2]);
+
+        assert_ne!(
+            Hashable(Vec2::new(0.0, 1.0)),
+            Hashable(Vec2::new(-0.0, 1.0))
+        );
+        assert_eq!(
+            Hashable(Vec4::splat(f32::NAN)),
+            Hashable(Vec4::splat(f32::NAN))
+        );
+    }
+}
@@ -21,5 +21,52 @@ pub use vec4::*;
 pub mod quaternion;
 pub use quaternion::*;
 
+pub mod dquaternion;
+pub use dquaternion::*;
+
+pub mod mat3;
+pub use mat3::*;
+
 pub mod mat4;
 pub use mat4::*;
+
+pub mod color;
+pub use color::*;
+
+pub mod convention;
+pub use convention::*;
+
+pub mod easing;
+pub use easing::*;
+
+pub mod hashable;
+pub use hashable::*;
+
+pub mod polygon;
+pub use polygon::*;
+
+pub mod rect;
+pub use rect::*;
+
+pub mod scalar;
+pub use scalar::*;
+
+pub mod spline;
+pub use spline::*;
+
+pub mod traits;
+pub use traits::*;
+
+#[cfg(feature = "num-traits")]
+mod num;
+
+#[cfg(feature = "approx")]
+mod approx_eq;
+
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "rand")]
+pub use random::*;
+
+#[cfg(feature = "simd")]
+mod simd;
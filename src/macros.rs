@@ -29,3 +29,94 @@ macro_rules! swizzle {
 macro_rules! swizzle {
     ($($members:ident),+) => {};
 }
+
+macro_rules! byte_conversions {
+    ($t:ident, $($members:ident),+) => {
+        byte_conversions!(@impl $t, to_le_bytes, from_le_bytes, "little", $($members),+);
+        byte_conversions!(@impl $t, to_be_bytes, from_be_bytes, "big", $($members),+);
+    };
+    (@impl $t:ident, $to:ident, $from:ident, $endian:literal, $($members:ident),+) => {
+        impl $t {
+            #[doc = concat!("Returns the components of `self` as a byte array in ", $endian, "-endian byte order")]
+            pub fn $to(&self) -> [u8; std::mem::size_of::<$t>()] {
+                let mut res = [0; std::mem::size_of::<$t>()];
+                let mut chunks = res.chunks_exact_mut(4);
+                $(
+                    chunks.next().unwrap().copy_from_slice(&self.$members.$to());
+                )+
+                res
+            }
+
+            #[doc = concat!("Creates a value from its components as a byte array in ", $endian, "-endian byte order")]
+            pub fn $from(bytes: [u8; std::mem::size_of::<$t>()]) -> Self {
+                let mut chunks = bytes.chunks_exact(4);
+                Self {
+                    $(
+                        $members: f32::$from(chunks.next().unwrap().try_into().unwrap()),
+                    )+
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that `$res` is finite if feature `debug-validate` is enabled.
+///
+/// The panic message contains `$res` as well as all given `$input`s.
+macro_rules! debug_validate {
+    ($op:literal, $res:expr $(, $input:ident)*) => {
+        #[cfg(feature = "debug-validate")]
+        debug_assert!(
+            $res.is_finite(),
+            concat!($op, " produced a non-finite value: {:?}" $(, ", ", stringify!($input), " = {:?}")*),
+            $res $(, $input)*
+        );
+    };
+}
+
+macro_rules! norm_conversions {
+    ($t:ident, $($members:ident),+) => {
+        norm_conversions!(@unorm $t, to_unorm8, from_unorm8, u8, $($members),+);
+        norm_conversions!(@unorm $t, to_unorm16, from_unorm16, u16, $($members),+);
+        norm_conversions!(@snorm $t, to_snorm8, from_snorm8, i8, $($members),+);
+        norm_conversions!(@snorm $t, to_snorm16, from_snorm16, i16, $($members),+);
+    };
+    (@unorm $t:ident, $to:ident, $from:ident, $int:ident, $($members:ident),+) => {
+        impl $t {
+            #[doc = concat!("Encodes `self` as unsigned normalized `", stringify!($int), "` values.")]
+            ///
+            /// Components are clamped to \[0; 1\] and rounded to the nearest representable value.
+            pub fn $to(&self) -> [$int; std::mem::size_of::<$t>() / 4] {
+                [$((self.$members.clamp(0.0, 1.0) * $int::MAX as f32).round() as $int),+]
+            }
+
+            #[doc = concat!("Decodes unsigned normalized `", stringify!($int), "` values created by [`", stringify!($to), "()`](Self::", stringify!($to), "())")]
+            pub fn $from(v: [$int; std::mem::size_of::<$t>() / 4]) -> Self {
+                let [$($members),+] = v;
+                Self {
+                    $($members: $members as f32 / $int::MAX as f32),+
+                }
+            }
+        }
+    };
+    (@snorm $t:ident, $to:ident, $from:ident, $int:ident, $($members:ident),+) => {
+        impl $t {
+            #[doc = concat!("Encodes `self` as signed normalized `", stringify!($int), "` values.")]
+            ///
+            /// Components are clamped to \[-1; 1\] and rounded to the nearest representable value.
+            pub fn $to(&self) -> [$int; std::mem::size_of::<$t>() / 4] {
+                [$((self.$members.clamp(-1.0, 1.0) * $int::MAX as f32).round() as $int),+]
+            }
+
+            #[doc = concat!("Decodes signed normalized `", stringify!($int), "` values created by [`", stringify!($to), "()`](Self::", stringify!($to), "())")]
+            ///
+            #[doc = concat!("Both `", stringify!($int), "::MIN` and `", stringify!($int), "::MIN + 1` decode to -1.")]
+            pub fn $from(v: [$int; std::mem::size_of::<$t>() / 4]) -> Self {
+                let [$($members),+] = v;
+                Self {
+                    $($members: ($members as f32 / $int::MAX as f32).max(-1.0)),+
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,329 @@
+use auto_ops::impl_op_ex;
+
+use crate::{Vec2, Vec3};
+
+/// A struct representing a 3x3 matrix.
+///
+/// It's values are stored in column-major order by default,
+/// as expected by OpenGL and accepted by all other APIs.
+/// To change this, use feature `mat-row-major`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Mat3 {
+    pub values: [f32; 3 * 3],
+}
+
+impl Default for Mat3 {
+    /// Creates the identity matrix.
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(not(feature = "mat-row-major"))]
+const fn cr(c: usize, r: usize) -> usize {
+    r + c * 3
+}
+#[cfg(feature = "mat-row-major")]
+const fn cr(c: usize, r: usize) -> usize {
+    r * 3 + c
+}
+
+impl Mat3 {
+    /// The identity matrix
+    pub const IDENTITY: Self = Self {
+        values: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    };
+
+    /// Creates a matrix from three columns, regardless of feature `mat-row-major`
+    pub const fn from_cols(c0: Vec3, c1: Vec3, c2: Vec3) -> Self {
+        let mut res = Self::IDENTITY;
+        let cols = [c0, c1, c2];
+
+        let mut c = 0;
+        while c < 3 {
+            res.values[cr(c, 0)] = cols[c].x;
+            res.values[cr(c, 1)] = cols[c].y;
+            res.values[cr(c, 2)] = cols[c].z;
+            c += 1;
+        }
+
+        res
+    }
+
+    /// Creates an orthonormal tangent frame with the columns tangent, bitangent and `normal`,
+    /// which transforms from tangent space to the space `normal` is given in.
+    ///
+    /// `normal` has to be normalized. The tangent is chosen arbitrarily but continuously,
+    /// using the method by Duff et al. (2017). Use [`tangent_frame_from_uv()`](Self::tangent_frame_from_uv())
+    /// if the frame has to line up with a texture, e.g. for normal mapping.
+    pub fn tangent_frame(normal: Vec3) -> Self {
+        let sign = 1.0f32.copysign(normal.z);
+        let a = -1.0 / (sign + normal.z);
+        let b = normal.x * normal.y * a;
+
+        let tangent = Vec3::new(
+            1.0 + sign * normal.x * normal.x * a,
+            sign * b,
+            -sign * normal.x,
+        );
+        let bitangent = Vec3::new(b, sign + normal.y * normal.y * a, -normal.y);
+        Self::from_cols(tangent, bitangent, normal)
+    }
+
+    /// Creates an orthonormal tangent frame (TBN matrix) for a triangle,
+    /// with the tangent following the u and the bitangent following the v texture coordinate.
+    ///
+    /// `edge1` and `edge2` are the triangle's edges from its first vertex,
+    /// `delta_uv1` and `delta_uv2` the corresponding differences in texture coordinates.
+    /// `normal` has to be normalized and is kept as is, the tangent is orthogonalized against it.
+    /// Mirrored texture mappings result in a flipped bitangent.
+    /// Falls back to [`tangent_frame()`](Self::tangent_frame()) for degenerate texture coordinates.
+    pub fn tangent_frame_from_uv(
+        normal: Vec3,
+        edge1: Vec3,
+        edge2: Vec3,
+        delta_uv1: Vec2,
+        delta_uv2: Vec2,
+    ) -> Self {
+        let r = delta_uv1.perp_dot(delta_uv2).recip();
+        let tangent = (edge1 * delta_uv2.y - edge2 * delta_uv1.y) * r;
+        let bitangent = (edge2 * delta_uv1.x - edge1 * delta_uv2.x) * r;
+
+        let Some(tangent) = tangent.reject_from(normal).try_normalize() else {
+            return Self::tangent_frame(normal);
+        };
+
+        let mut orthogonal = normal.cross(tangent);
+        if orthogonal.dot(bitangent) < 0.0 {
+            orthogonal = -orthogonal;
+        }
+        Self::from_cols(tangent, orthogonal, normal)
+    }
+
+    /// Returns `true` if all values of `self` are neither infinite nor NaN
+    pub fn is_finite(&self) -> bool {
+        self.values.iter().all(|v| v.is_finite())
+    }
+
+    /// Returns a value indexed by `column` and `row`
+    pub const fn get(&self, column: usize, row: usize) -> f32 {
+        self.values[cr(column, row)]
+    }
+
+    /// Sets the value indexed by `column` and `row` to `val`
+    pub fn set(&mut self, column: usize, row: usize, val: f32) {
+        self.values[cr(column, row)] = val;
+    }
+
+    /// Returns the cofactor of the value at `column` and `row`
+    fn cofactor_at(&self, column: usize, row: usize) -> f32 {
+        let (c0, c1) = ((column + 1) % 3, (column + 2) % 3);
+        let (r0, r1) = ((row + 1) % 3, (row + 2) % 3);
+
+        // cyclic indexing already yields the correctly signed cofactor for 3x3 matrices
+        self.get(c0, r0) * self.get(c1, r1) - self.get(c1, r0) * self.get(c0, r1)
+    }
+
+    /// Returns the determinant of `self`
+    pub fn determinant(&self) -> f32 {
+        (0..3)
+            .map(|c| self.get(c, 0) * self.cofactor_at(c, 0))
+            .sum()
+    }
+
+    /// Returns the transposed cofactor matrix of `self` scaled by `inv_det`
+    fn scaled_adjugate(&self, inv_det: f32) -> Mat3 {
+        let mut res = Mat3::IDENTITY;
+
+        for c in 0..3 {
+            for r in 0..3 {
+                res.values[cr(c, r)] = self.cofactor_at(r, c) * inv_det;
+            }
+        }
+
+        res
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// If `self` is not invertible, the result will contain non-finite values.
+    /// Use [`try_inverse()`](Self::try_inverse()) if that can happen.
+    #[must_use]
+    pub fn inverse(&self) -> Mat3 {
+        let res = self.scaled_adjugate(1.0 / self.determinant());
+        debug_validate!("Mat3::inverse", res, self);
+        res
+    }
+
+    /// Returns the inverse of `self`,
+    /// or `None` if `self` is singular or nearly singular, i.e. the absolute value of its determinant
+    /// is not greater than `epsilon`.
+    ///
+    /// The determinant scales with the values of the matrix, e.g. by `s^3` for a uniform scale `s`,
+    /// so `epsilon` should be chosen relative to the expected magnitude of `self`.
+    pub fn try_inverse(&self, epsilon: f32) -> Option<Mat3> {
+        let det = self.determinant();
+        if det.abs() <= epsilon || !det.is_finite() {
+            return None;
+        }
+
+        let res = self.scaled_adjugate(1.0 / det);
+        res.is_finite().then_some(res)
+    }
+
+    /// Returns a transposed copy of `self`.
+    #[must_use]
+    pub fn transposed(&self) -> Mat3 {
+        let mut res = Mat3::IDENTITY;
+
+        for c in 0..3 {
+            for r in 0..3 {
+                res.values[cr(r, c)] = self.values[cr(c, r)];
+            }
+        }
+
+        res
+    }
+
+    /// Returns the underlying values as a slice
+    pub fn as_slice(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Returns the underlying values as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.values
+    }
+
+    /// Returns the underlying values as a pointer with no size information
+    pub fn as_ptr(&self) -> *const f32 {
+        self.values.as_ptr()
+    }
+
+    /// Returns the underlying values as a mutable pointer with no size information
+    pub fn as_mut_ptr(&mut self) -> *mut f32 {
+        self.values.as_mut_ptr()
+    }
+}
+
+impl_op_ex!(*|a: &Mat3, b: &Mat3| -> Mat3 {
+    let mut res = Mat3::IDENTITY;
+
+    for r in 0..3 {
+        for c in 0..3 {
+            res.values[cr(c, r)] = a.values[cr(0, r)] * b.values[cr(c, 0)]
+                + a.values[cr(1, r)] * b.values[cr(c, 1)]
+                + a.values[cr(2, r)] * b.values[cr(c, 2)];
+        }
+    }
+
+    debug_validate!("Mat3 * Mat3", res, a, b);
+    res
+});
+
+impl_op_ex!(*|a: &Mat3, b: &Vec3| -> Vec3 {
+    let res = Vec3 {
+        x: a.values[cr(0, 0)] * b.x + a.values[cr(1, 0)] * b.y + a.values[cr(2, 0)] * b.z,
+        y: a.values[cr(0, 1)] * b.x + a.values[cr(1, 1)] * b.y + a.values[cr(2, 1)] * b.z,
+        z: a.values[cr(0, 2)] * b.x + a.values[cr(1, 2)] * b.y + a.values[cr(2, 2)] * b.z,
+    };
+    debug_validate!("Mat3 * Vec3", res, a, b);
+    res
+});
+
+impl From<[f32; 9]> for Mat3 {
+    fn from(d: [f32; 9]) -> Self {
+        Self { values: d }
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for Mat3 {
+    type Output = f32;
+
+    fn index(&self, (c, r): (usize, usize)) -> &Self::Output {
+        &self.values[cr(c, r)]
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for Mat3 {
+    fn index_mut(&mut self, (c, r): (usize, usize)) -> &mut Self::Output {
+        &mut self.values[cr(c, r)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse() {
+        let mut m = Mat3::IDENTITY;
+        m[(0, 0)] = 2.0;
+        m[(1, 0)] = 1.0;
+        m[(2, 1)] = 3.0;
+        m[(0, 2)] = -1.0;
+        m[(2, 2)] = 4.0;
+
+        assert_eq!(m.determinant(), 5.0);
+        let i = m.inverse();
+        for (a, b) in (m * i).values.iter().zip(Mat3::IDENTITY.values) {
+            assert!((a - b).abs() < 1e-6);
+        }
+        assert_eq!(m.transposed().transposed(), m);
+
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(m * v, Vec3::new(4.0, 11.0, 11.0));
+
+        m[(1, 1)] = 0.0;
+        m[(2, 1)] = 0.0;
+        m[(1, 2)] = 0.0;
+        assert_eq!(m.try_inverse(0.0), None);
+
+        // nearly singular matrices
+        let mut scale = Mat3::IDENTITY;
+        scale[(0, 0)] = 1e-20;
+        assert_eq!(scale.try_inverse(1e-6), None);
+        let mut collinear = Mat3::IDENTITY;
+        collinear[(1, 0)] = 1.0;
+        collinear[(1, 1)] = 1e-7;
+        assert!(collinear.try_inverse(0.0).is_some());
+        assert_eq!(collinear.try_inverse(1e-6), None);
+        assert!(Mat3::IDENTITY.try_inverse(1e-6).is_some());
+    }
+
+    #[test]
+    fn tangent_frame() {
+        let normals = [
+            Vec3::X,
+            Vec3::NEG_Y,
+            Vec3::Z,
+            Vec3::NEG_Z,
+            Vec3::new(1.0, -2.0, 0.5).normalized(),
+        ];
+        for n in normals {
+            let m = Mat3::tangent_frame(n);
+            let t = m * Vec3::X;
+            let b = m * Vec3::Y;
+            assert!((m * Vec3::Z - n).magnitude() < 1e-6);
+            assert!((t.magnitude() - 1.0).abs() < 1e-5 && (b.magnitude() - 1.0).abs() < 1e-5);
+            assert!((t.cross(b) - n).magnitude() < 1e-5);
+        }
+
+        // a quad in the xz plane with u along +x and v along -z
+        let n = Vec3::Y;
+        let edge1 = Vec3::new(2.0, 0.0, 0.0);
+        let edge2 = Vec3::new(0.0, 0.0, -2.0);
+        let m = Mat3::tangent_frame_from_uv(n, edge1, edge2, Vec2::X, Vec2::Y);
+        assert!((m * Vec3::X - Vec3::X).magnitude() < 1e-6);
+        assert!((m * Vec3::Y - Vec3::NEG_Z).magnitude() < 1e-6);
+
+        // mirrored v
+        let m = Mat3::tangent_frame_from_uv(n, edge1, edge2, Vec2::X, Vec2::NEG_Y);
+        assert!((m * Vec3::Y - Vec3::Z).magnitude() < 1e-6);
+
+        let m = Mat3::tangent_frame_from_uv(n, edge1, edge2, Vec2::ZERO, Vec2::ZERO);
+        assert_eq!(m, Mat3::tangent_frame(n));
+    }
+}
@@ -1,19 +1,56 @@
-use auto_ops::impl_op_ex;
+use std::fmt::{Debug, Display};
 
-use crate::{Quaternion, Vec3, Vec4};
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+use crate::{Lerp, Mat3, NdcConvention, Quaternion, Rect, Slerp, Vec2, Vec3, Vec4};
 
 /// A struct representing a 4x4 matrix.
 ///
 /// It's values are stored in column-major order by default,
 /// as expected by OpenGL and accepted by all other APIs.
 /// To change this, use feature `mat-row-major`
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Mat4 {
     pub values: [f32; 4 * 4],
 }
 
+/// Prints the values of `self` row by row, in logical order regardless of feature `mat-row-major`
+impl Display for Mat4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in 0..4 {
+            if r != 0 {
+                writeln!(f)?;
+            }
+            let [a, b, c, d] = [0, 1, 2, 3].map(|c| self.values[cr(c, r)]);
+            write!(f, "({a}, {b}, {c}, {d})")?;
+        }
+        Ok(())
+    }
+}
+
+/// `{:?}` prints the underlying values in storage order.
+///
+/// `{:#?}` prints the values row by row in logical order, regardless of feature `mat-row-major`.
+impl Debug for Mat4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !f.alternate() {
+            return f
+                .debug_struct("Mat4")
+                .field("values", &self.values)
+                .finish();
+        }
+
+        writeln!(f, "Mat4 [")?;
+        for r in 0..4 {
+            let [a, b, c, d] = [0, 1, 2, 3].map(|c| self.values[cr(c, r)]);
+            writeln!(f, "    ({a:?}, {b:?}, {c:?}, {d:?}),")?;
+        }
+        write!(f, "]")
+    }
+}
+
 impl Default for Mat4 {
     /// Creates the identity matrix.
     fn default() -> Self {
@@ -38,6 +75,93 @@ impl Mat4 {
         ],
     };
 
+    /// The matrix with all values set to zero
+    pub const ZERO: Self = Self {
+        values: [0.0; 4 * 4],
+    };
+
+    /// Converts OpenGL clip space to Vulkan clip space when multiplied from the left
+    /// onto an OpenGL projection matrix, e.g. `Mat4::OPENGL_TO_VULKAN * proj`.
+    ///
+    /// Flips the y axis (Vulkan's NDC y axis points down) and maps z from \[-1; 1\] to \[0; 1\].
+    /// Note that the `_vulkan` projection constructors of this crate only change the depth range,
+    /// so combining this matrix with them is not the same as using the `_vulkan` variant.
+    pub const OPENGL_TO_VULKAN: Self = {
+        let mut res = Self::IDENTITY;
+        res.values[cr(1, 1)] = -1.0;
+        res.values[cr(2, 2)] = 0.5;
+        res.values[cr(3, 2)] = 0.5;
+        res
+    };
+
+    /// The inverse of [`OPENGL_TO_VULKAN`](Self::OPENGL_TO_VULKAN).
+    ///
+    /// Flips the y axis and maps z from \[0; 1\] to \[-1; 1\].
+    pub const VULKAN_TO_OPENGL: Self = {
+        let mut res = Self::IDENTITY;
+        res.values[cr(1, 1)] = -1.0;
+        res.values[cr(2, 2)] = 2.0;
+        res.values[cr(3, 2)] = -1.0;
+        res
+    };
+
+    /// Converts OpenGL clip space to WebGPU (wgpu) clip space when multiplied from the left
+    /// onto an OpenGL projection matrix, e.g. `Mat4::OPENGL_TO_WGPU * proj`.
+    ///
+    /// Maps z from \[-1; 1\] to \[0; 1\]. The y axis points up in both conventions.
+    pub const OPENGL_TO_WGPU: Self = {
+        let mut res = Self::IDENTITY;
+        res.values[cr(2, 2)] = 0.5;
+        res.values[cr(3, 2)] = 0.5;
+        res
+    };
+
+    /// Creates a matrix with `d` on its diagonal and zeros everywhere else.
+    pub const fn from_diagonal(d: Vec4) -> Self {
+        let mut res = Self::ZERO;
+
+        res.values[cr(0, 0)] = d.x;
+        res.values[cr(1, 1)] = d.y;
+        res.values[cr(2, 2)] = d.z;
+        res.values[cr(3, 3)] = d.w;
+
+        res
+    }
+
+    /// Creates a matrix from four columns, regardless of feature `mat-row-major`
+    pub const fn from_cols(c0: Vec4, c1: Vec4, c2: Vec4, c3: Vec4) -> Self {
+        let mut res = Self::IDENTITY;
+        let cols = [c0, c1, c2, c3];
+
+        let mut c = 0;
+        while c < 4 {
+            res.values[cr(c, 0)] = cols[c].x;
+            res.values[cr(c, 1)] = cols[c].y;
+            res.values[cr(c, 2)] = cols[c].z;
+            res.values[cr(c, 3)] = cols[c].w;
+            c += 1;
+        }
+
+        res
+    }
+
+    /// Creates a matrix from four rows, regardless of feature `mat-row-major`
+    pub const fn from_rows(r0: Vec4, r1: Vec4, r2: Vec4, r3: Vec4) -> Self {
+        let mut res = Self::IDENTITY;
+        let rows = [r0, r1, r2, r3];
+
+        let mut r = 0;
+        while r < 4 {
+            res.values[cr(0, r)] = rows[r].x;
+            res.values[cr(1, r)] = rows[r].y;
+            res.values[cr(2, r)] = rows[r].z;
+            res.values[cr(3, r)] = rows[r].w;
+            r += 1;
+        }
+
+        res
+    }
+
     /// Creates a 3D translation matrix.
     pub const fn translate(t: Vec3) -> Self {
         let mut res = Self::IDENTITY;
@@ -72,6 +196,102 @@ impl Mat4 {
         res
     }
 
+    /// Creates a 3D rotation matrix of `radians` radians around the x axis.
+    ///
+    /// This is equivalent to rotating by [`Quaternion::axis_angle()`] around (1, 0, 0).
+    pub fn rotate_x(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::rotate_x_sin_cos(sin, cos)
+    }
+
+    /// Creates a 3D rotation matrix around the x axis from the sine and cosine of the angle.
+    ///
+    /// Unlike [`rotate_x()`](Self::rotate_x()), this can be used in const contexts.
+    pub const fn rotate_x_sin_cos(sin: f32, cos: f32) -> Self {
+        Self::from_cols(
+            Vec4::new(1.0, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, cos, sin, 0.0),
+            Vec4::new(0.0, -sin, cos, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Creates a 3D rotation matrix of `radians` radians around the y axis.
+    ///
+    /// This is equivalent to rotating by [`Quaternion::axis_angle()`] around (0, 1, 0).
+    pub fn rotate_y(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::rotate_y_sin_cos(sin, cos)
+    }
+
+    /// Creates a 3D rotation matrix around the y axis from the sine and cosine of the angle.
+    ///
+    /// Unlike [`rotate_y()`](Self::rotate_y()), this can be used in const contexts.
+    pub const fn rotate_y_sin_cos(sin: f32, cos: f32) -> Self {
+        Self::from_cols(
+            Vec4::new(cos, 0.0, -sin, 0.0),
+            Vec4::new(0.0, 1.0, 0.0, 0.0),
+            Vec4::new(sin, 0.0, cos, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Creates a 3D rotation matrix of `radians` radians around the z axis.
+    ///
+    /// This is equivalent to rotating by [`Quaternion::axis_angle()`] around (0, 0, 1).
+    pub fn rotate_z(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::rotate_z_sin_cos(sin, cos)
+    }
+
+    /// Creates a 3D rotation matrix around the z axis from the sine and cosine of the angle.
+    ///
+    /// Unlike [`rotate_z()`](Self::rotate_z()), this can be used in const contexts.
+    pub const fn rotate_z_sin_cos(sin: f32, cos: f32) -> Self {
+        Self::from_cols(
+            Vec4::new(cos, sin, 0.0, 0.0),
+            Vec4::new(-sin, cos, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 1.0, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Creates a 3D rotation matrix of `radians` radians around `axis`.
+    ///
+    /// This is equivalent to `Mat4::rotate(Quaternion::axis_angle(axis, radians))`.
+    pub fn rotate_axis_angle(axis: Vec3, radians: f32) -> Self {
+        let a = axis.normalized();
+        let (sin, cos) = radians.sin_cos();
+        let t = 1.0 - cos;
+
+        Self::from_cols(
+            Vec4::new(
+                t * a.x * a.x + cos,
+                t * a.x * a.y + sin * a.z,
+                t * a.x * a.z - sin * a.y,
+                0.0,
+            ),
+            Vec4::new(
+                t * a.x * a.y - sin * a.z,
+                t * a.y * a.y + cos,
+                t * a.y * a.z + sin * a.x,
+                0.0,
+            ),
+            Vec4::new(
+                t * a.x * a.z + sin * a.y,
+                t * a.y * a.z - sin * a.x,
+                t * a.z * a.z + cos,
+                0.0,
+            ),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Extracts the rotation of `self`, see [`Quaternion::from_mat4()`]
+    pub fn to_quaternion(&self) -> Quaternion {
+        Quaternion::from_mat4(self)
+    }
+
     /// Creates a 3D scale matrix.
     pub const fn scale(s: Vec3) -> Self {
         let mut res = Self::IDENTITY;
@@ -83,6 +303,23 @@ impl Mat4 {
         res
     }
 
+    /// Creates a matrix that mirrors points about the plane through `point` with the given `normal`.
+    ///
+    /// `normal` does not need to be normalized.
+    /// Note that a reflection flips the winding order of transformed triangles.
+    pub fn reflect(point: Vec3, normal: Vec3) -> Self {
+        let n = normal.normalized();
+        let d = 2.0 * n.dot(point);
+        let n2 = n * 2.0;
+
+        Self::from_cols(
+            Vec4::new(1.0 - n2.x * n.x, -n2.y * n.x, -n2.z * n.x, 0.0),
+            Vec4::new(-n2.x * n.y, 1.0 - n2.y * n.y, -n2.z * n.y, 0.0),
+            Vec4::new(-n2.x * n.z, -n2.y * n.z, 1.0 - n2.z * n.z, 0.0),
+            Vec4::new(d * n.x, d * n.y, d * n.z, 1.0),
+        )
+    }
+
     /// Creates a 3D local-to-world/object-to-world matrix.
     ///
     /// When multiplying this matrix by a vector, it will be
@@ -102,6 +339,72 @@ impl Mat4 {
         Self::scale(1.0 / s) * Self::rotate(-r) * Self::translate(-t)
     }
 
+    /// Creates a left-handed view matrix for a camera at `eye` looking at `target`.
+    ///
+    /// The camera looks along +z in view space, as expected by the projection matrices of this crate.
+    pub fn look_at_lh(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let forward = (target - eye).normalized();
+        let right = up.cross(forward).normalized();
+        let up = forward.cross(right);
+
+        Self::view_from_axes(eye, right, up, forward)
+    }
+
+    /// Creates a right-handed view matrix for a camera at `eye` looking at `target`.
+    ///
+    /// The camera looks along -z in view space, like `gluLookAt()`.
+    pub fn look_at_rh(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let forward = (target - eye).normalized();
+        let right = forward.cross(up).normalized();
+        let up = right.cross(forward);
+
+        Self::view_from_axes(eye, right, up, -forward)
+    }
+
+    /// Creates a view matrix for a camera at `eye` whose view space x, y and z axes
+    /// point along the given world space axes
+    fn view_from_axes(eye: Vec3, x: Vec3, y: Vec3, z: Vec3) -> Self {
+        let mut res = Self::IDENTITY;
+
+        res.values[cr(0, 0)] = x.x;
+        res.values[cr(1, 0)] = x.y;
+        res.values[cr(2, 0)] = x.z;
+        res.values[cr(3, 0)] = -x.dot(eye);
+
+        res.values[cr(0, 1)] = y.x;
+        res.values[cr(1, 1)] = y.y;
+        res.values[cr(2, 1)] = y.z;
+        res.values[cr(3, 1)] = -y.dot(eye);
+
+        res.values[cr(0, 2)] = z.x;
+        res.values[cr(1, 2)] = z.y;
+        res.values[cr(2, 2)] = z.z;
+        res.values[cr(3, 2)] = -z.dot(eye);
+
+        res
+    }
+
+    /// Returns the world space position and orientation of the camera
+    /// described by the view matrix `self`.
+    ///
+    /// This is the inverse of [`world_to_local()`](Self::world_to_local()) with a scale of one,
+    /// i.e. `Mat4::world_to_local(pos, rot, Vec3::ONE)` returns a view matrix for which this
+    /// function returns `(pos, rot)`.
+    /// `self` must not contain any scale or projection.
+    pub fn view_to_camera(&self) -> (Vec3, Quaternion) {
+        // the upper 3x3 block is the transposed camera rotation,
+        // so its rows are the camera's axes in world space
+        let right = Vec3::new(self.get(0, 0), self.get(1, 0), self.get(2, 0));
+        let up = Vec3::new(self.get(0, 1), self.get(1, 1), self.get(2, 1));
+        let forward = Vec3::new(self.get(0, 2), self.get(1, 2), self.get(2, 2));
+
+        // translation is -R^T * pos
+        let t = Vec3::new(self.get(3, 0), self.get(3, 1), self.get(3, 2));
+        let pos = -(right * t.x + up * t.y + forward * t.z);
+
+        (pos, Quaternion::from_axes(right, up, forward))
+    }
+
     /// Creates an orthographic projection matrix
     /// with z mapped to \[0; 1\], as expected by Vulkan.
     pub fn orthographic_vulkan(
@@ -130,6 +433,17 @@ impl Mat4 {
         res.values[cr(2, 2)] = e;
         res.values[cr(3, 2)] = f;
 
+        debug_validate!(
+            "Mat4::orthographic_vulkan",
+            res,
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far
+        );
+
         res
     }
 
@@ -161,6 +475,17 @@ impl Mat4 {
         res.values[cr(2, 2)] = e;
         res.values[cr(3, 2)] = f;
 
+        debug_validate!(
+            "Mat4::orthographic_opengl",
+            res,
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far
+        );
+
         res
     }
 
@@ -195,6 +520,17 @@ impl Mat4 {
         res.values[cr(2, 2)] = 1.0 / e;
         res.values[cr(3, 2)] = -f / e;
 
+        debug_validate!(
+            "Mat4::inverse_orthographic_vulkan",
+            res,
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far
+        );
+
         res
     }
 
@@ -229,20 +565,30 @@ impl Mat4 {
         res.values[cr(2, 2)] = 1.0 / e;
         res.values[cr(3, 2)] = -f / e;
 
+        debug_validate!(
+            "Mat4::inverse_orthographic_opengl",
+            res,
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far
+        );
+
         res
     }
 
-    /// Creates a perspective projection matrix
-    /// with z mapped to \[0; 1\], as expected by Vulkan.
-    pub fn perspective_vulkan(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+    /// Creates a perspective projection matrix that maps view space depth `z` to `(c * z + d) / z`
+    fn perspective_with_depth(fov_rad: f32, aspect: f32, c: f32, d: f32) -> Self {
         let mut res = Self::IDENTITY;
         let thfov = (fov_rad * 0.5).tan();
 
         res.values[cr(0, 0)] = 1.0 / (thfov * aspect);
         res.values[cr(1, 1)] = 1.0 / thfov;
 
-        res.values[cr(2, 2)] = far / (far - near);
-        res.values[cr(3, 2)] = (-far * near) / (far - near);
+        res.values[cr(2, 2)] = c;
+        res.values[cr(3, 2)] = d;
 
         res.values[cr(2, 3)] = 1.0;
         res.values[cr(3, 3)] = 0.0;
@@ -250,192 +596,1434 @@ impl Mat4 {
         res
     }
 
-    /// Creates a perspective projection matrix
-    /// with z mapped to \[-1; 1\], as expected by OpenGL.
-    pub fn perspective_opengl(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+    /// Creates the inverse of [`perspective_with_depth()`](Self::perspective_with_depth())
+    fn inverse_perspective_with_depth(fov_rad: f32, aspect: f32, c: f32, d: f32) -> Self {
         let mut res = Self::IDENTITY;
         let thfov = (fov_rad * 0.5).tan();
 
-        res.values[cr(0, 0)] = 1.0 / (thfov * aspect);
-        res.values[cr(1, 1)] = 1.0 / thfov;
+        res.values[cr(0, 0)] = thfov * aspect;
+        res.values[cr(1, 1)] = thfov;
 
-        res.values[cr(2, 2)] = (far + near) / (far - near);
-        res.values[cr(3, 2)] = (-2.0 * far * near) / (far - near);
+        res.values[cr(3, 2)] = 1.0;
 
-        res.values[cr(2, 3)] = 1.0;
-        res.values[cr(3, 3)] = 0.0;
+        res.values[cr(2, 2)] = 0.0;
+        res.values[cr(2, 3)] = 1.0 / d;
+        res.values[cr(3, 3)] = -c / d;
 
         res
     }
 
-    /// Creates an inverse perspective matrix
+    /// Creates a perspective projection matrix
     /// with z mapped to \[0; 1\], as expected by Vulkan.
-    ///
-    /// This matrix can be used to reconstruct the world position from a uv/depth pair in a shader.
-    /// This pseudo code can be used:
-    /// ```GLSL
-    /// vec4 clipPos = vec4(uv.xy, depth, 1.0);
-    /// vec4 worldPos = invProjection * clipPos;
-    /// worldPos /= worldPos.w;
-    /// ```
-    pub fn inverse_perspective_vulkan(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
-        let mut res = Self::IDENTITY;
-
-        let thfov = (fov_rad * 0.5).tan();
+    pub fn perspective_vulkan(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
         let c = far / (far - near);
         let d = (-far * near) / (far - near);
+        let res = Self::perspective_with_depth(fov_rad, aspect, c, d);
 
-        res.values[cr(0, 0)] = thfov * aspect;
-        res.values[cr(1, 1)] = thfov;
-
-        res.values[cr(3, 2)] = 1.0;
-
-        res.values[cr(2, 2)] = 0.0;
-        res.values[cr(2, 3)] = 1.0 / d;
-        res.values[cr(3, 3)] = -c / d;
+        debug_validate!("Mat4::perspective_vulkan", res, fov_rad, near, far, aspect);
 
         res
     }
 
-    /// Creates an inverse perspective matrix
+    /// Creates a perspective projection matrix
     /// with z mapped to \[-1; 1\], as expected by OpenGL.
-    ///
-    /// This matrix can be used to reconstruct the world position from a uv/depth pair in a shader.
-    /// This pseudo code can be used:
-    /// ```GLSL
-    /// vec4 clipPos = vec4(uv.xy, depth * 2.0 - 1.0, 1.0);
-    /// vec4 worldPos = invProjection * clipPos;
-    /// worldPos /= worldPos.w;
-    /// ```
-    pub fn inverse_perspective_opengl(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
-        let mut res = Self::IDENTITY;
-
-        let thfov = (fov_rad * 0.5).tan();
+    pub fn perspective_opengl(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
         let c = (far + near) / (far - near);
         let d = (-2.0 * far * near) / (far - near);
+        let res = Self::perspective_with_depth(fov_rad, aspect, c, d);
 
-        res.values[cr(0, 0)] = thfov * aspect;
-        res.values[cr(1, 1)] = thfov;
+        debug_validate!("Mat4::perspective_opengl", res, fov_rad, near, far, aspect);
 
-        res.values[cr(3, 2)] = 1.0;
+        res
+    }
 
-        res.values[cr(2, 2)] = 0.0;
-        res.values[cr(2, 3)] = 1.0 / d;
-        res.values[cr(3, 3)] = -c / d;
+    /// Creates a perspective projection matrix
+    /// with z mapped to \[1; 0\] (reversed depth), as expected by Vulkan.
+    ///
+    /// Reversed depth distributes floating point depth precision much more evenly,
+    /// the depth test has to be inverted to use it (e.g. `GREATER` instead of `LESS`).
+    pub fn perspective_vulkan_reversed_z(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+        let c = -near / (far - near);
+        let d = (far * near) / (far - near);
+        let res = Self::perspective_with_depth(fov_rad, aspect, c, d);
+
+        debug_validate!(
+            "Mat4::perspective_vulkan_reversed_z",
+            res,
+            fov_rad,
+            near,
+            far,
+            aspect
+        );
 
         res
     }
 
-    /// Returns a value indexed by `column` and `row`
-    pub const fn get(&self, column: usize, row: usize) -> f32 {
-        self.values[cr(column, row)]
-    }
+    /// Creates a perspective projection matrix
+    /// with z mapped to \[1; -1\] (reversed depth), as expected by OpenGL.
+    ///
+    /// Note that reversed depth only improves precision with OpenGL if the depth range is
+    /// changed to \[0; 1\] via `glClipControl`, in which case
+    /// [`perspective_vulkan_reversed_z()`](Self::perspective_vulkan_reversed_z()) should be used.
+    pub fn perspective_opengl_reversed_z(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+        let c = -(far + near) / (far - near);
+        let d = (2.0 * far * near) / (far - near);
+        let res = Self::perspective_with_depth(fov_rad, aspect, c, d);
+
+        debug_validate!(
+            "Mat4::perspective_opengl_reversed_z",
+            res,
+            fov_rad,
+            near,
+            far,
+            aspect
+        );
 
-    /// Sets the value indexed by `column` and `row` to `val`
-    pub fn set(&mut self, column: usize, row: usize, val: f32) {
-        self.values[cr(column, row)] = val;
+        res
     }
 
-    /// Returns a transposed copy of `self`.
-    #[must_use]
-    pub fn transposed(&self) -> Mat4 {
-        let mut res = Mat4::IDENTITY;
+    /// Creates a perspective projection matrix with an infinitely distant far plane
+    /// and z mapped to \[0; 1\], as expected by Vulkan.
+    pub fn perspective_infinite_vulkan(fov_rad: f32, near: f32, aspect: f32) -> Self {
+        let res = Self::perspective_with_depth(fov_rad, aspect, 1.0, -near);
 
-        for c in 0..4 {
-            for r in 0..4 {
-                res.values[cr(r, c)] = self.values[cr(c, r)];
-            }
-        }
+        debug_validate!(
+            "Mat4::perspective_infinite_vulkan",
+            res,
+            fov_rad,
+            near,
+            aspect
+        );
 
         res
     }
 
-    /// Returns the underlying values as a slice
-    pub fn as_slice(&self) -> &[f32] {
-        &self.values
-    }
+    /// Creates a perspective projection matrix with an infinitely distant far plane
+    /// and z mapped to \[-1; 1\], as expected by OpenGL.
+    pub fn perspective_infinite_opengl(fov_rad: f32, near: f32, aspect: f32) -> Self {
+        let res = Self::perspective_with_depth(fov_rad, aspect, 1.0, -2.0 * near);
 
-    /// Returns the underlying values as a mutable slice
-    pub fn as_mut_slice(&mut self) -> &mut [f32] {
-        &mut self.values
-    }
+        debug_validate!(
+            "Mat4::perspective_infinite_opengl",
+            res,
+            fov_rad,
+            near,
+            aspect
+        );
 
-    /// Returns the underlying values as a pointer with no size information
-    pub fn as_ptr(&self) -> *const f32 {
-        self.values.as_ptr()
+        res
     }
 
-    /// Returns the underlying values as a mutable pointer with no size information
-    pub fn as_mut_ptr(&mut self) -> *mut f32 {
-        self.values.as_mut_ptr()
+    /// Creates a perspective projection matrix with an infinitely distant far plane
+    /// and z mapped to \[1; 0\] (reversed depth), as expected by Vulkan.
+    ///
+    /// See [`perspective_vulkan_reversed_z()`](Self::perspective_vulkan_reversed_z()).
+    pub fn perspective_infinite_vulkan_reversed_z(fov_rad: f32, near: f32, aspect: f32) -> Self {
+        let res = Self::perspective_with_depth(fov_rad, aspect, 0.0, near);
+
+        debug_validate!(
+            "Mat4::perspective_infinite_vulkan_reversed_z",
+            res,
+            fov_rad,
+            near,
+            aspect
+        );
+
+        res
     }
-}
 
-impl_op_ex!(*|a: &Mat4, b: &Mat4| -> Mat4 {
-    let mut res = Mat4::IDENTITY;
+    /// Creates a perspective projection matrix with an infinitely distant far plane
+    /// and z mapped to \[1; -1\] (reversed depth), as expected by OpenGL.
+    ///
+    /// See [`perspective_opengl_reversed_z()`](Self::perspective_opengl_reversed_z()).
+    pub fn perspective_infinite_opengl_reversed_z(fov_rad: f32, near: f32, aspect: f32) -> Self {
+        let res = Self::perspective_with_depth(fov_rad, aspect, -1.0, 2.0 * near);
+
+        debug_validate!(
+            "Mat4::perspective_infinite_opengl_reversed_z",
+            res,
+            fov_rad,
+            near,
+            aspect
+        );
 
-    for r in 0..4 {
-        for c in 0..4 {
-            res.values[cr(c, r)] = a.values[cr(0, r)] * b.values[cr(c, 0)]
-                + a.values[cr(1, r)] * b.values[cr(c, 1)]
-                + a.values[cr(2, r)] * b.values[cr(c, 2)]
-                + a.values[cr(3, r)] * b.values[cr(c, 3)];
-        }
+        res
     }
 
-    res
-});
+    /// Creates an off-center perspective projection matrix that maps view space depth `z` to `(c * z + d) / z`
+    fn frustum_with_depth(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        c: f32,
+        d: f32,
+    ) -> Self {
+        let mut res = Self::ZERO;
 
-impl_op_ex!(*|a: &Mat4, b: &Vec4| -> Vec4 {
-    Vec4 {
-        x: a.values[cr(0, 0)] * b.x
-            + a.values[cr(1, 0)] * b.y
-            + a.values[cr(2, 0)] * b.z
-            + a.values[cr(3, 0)] * b.w,
-        y: a.values[cr(0, 1)] * b.x
-            + a.values[cr(1, 1)] * b.y
-            + a.values[cr(2, 1)] * b.z
-            + a.values[cr(3, 1)] * b.w,
-        z: a.values[cr(0, 2)] * b.x
-            + a.values[cr(1, 2)] * b.y
-            + a.values[cr(2, 2)] * b.z
-            + a.values[cr(3, 2)] * b.w,
-        w: a.values[cr(0, 3)] * b.x
-            + a.values[cr(1, 3)] * b.y
-            + a.values[cr(2, 3)] * b.z
-            + a.values[cr(3, 3)] * b.w,
-    }
-});
+        res.values[cr(0, 0)] = (2.0 * near) / (right - left);
+        res.values[cr(2, 0)] = -(right + left) / (right - left);
 
-impl_op_ex!(*|a: &Mat4, b: &Vec3| -> Vec3 {
-    Vec3 {
-        x: a.values[cr(0, 0)] * b.x + a.values[cr(1, 0)] * b.y + a.values[cr(2, 0)] * b.z,
-        y: a.values[cr(0, 1)] * b.x + a.values[cr(1, 1)] * b.y + a.values[cr(2, 1)] * b.z,
-        z: a.values[cr(0, 2)] * b.x + a.values[cr(1, 2)] * b.y + a.values[cr(2, 2)] * b.z,
-    }
-});
+        res.values[cr(1, 1)] = (2.0 * near) / (top - bottom);
+        res.values[cr(2, 1)] = -(top + bottom) / (top - bottom);
 
-impl From<[f32; 16]> for Mat4 {
-    fn from(d: [f32; 16]) -> Self {
-        Self { values: d }
-    }
-}
+        res.values[cr(2, 2)] = c;
+        res.values[cr(3, 2)] = d;
 
-impl From<[[f32; 4]; 4]> for Mat4 {
-    fn from(d: [[f32; 4]; 4]) -> Self {
-        Self {
-            values: [
-                d[0][0], d[0][1], d[0][2], d[0][3], d[1][0], d[1][1], d[1][2], d[1][3], d[2][0],
-                d[2][1], d[2][2], d[2][3], d[3][0], d[3][1], d[3][2], d[3][3],
-            ],
-        }
-    }
-}
+        res.values[cr(2, 3)] = 1.0;
 
-impl std::ops::Index<(usize, usize)> for Mat4 {
-    type Output = f32;
+        res
+    }
 
-    fn index(&self, (c, r): (usize, usize)) -> &Self::Output {
+    /// Creates an off-center perspective projection matrix
+    /// with z mapped to \[0; 1\], as expected by Vulkan.
+    ///
+    /// `left`, `right`, `bottom` and `top` are the bounds of the frustum on the near plane.
+    /// With symmetric bounds this is equivalent to [`perspective_vulkan()`](Self::perspective_vulkan()).
+    pub fn frustum_vulkan(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let c = far / (far - near);
+        let d = (-far * near) / (far - near);
+        let res = Self::frustum_with_depth(left, right, bottom, top, near, c, d);
+
+        debug_validate!(
+            "Mat4::frustum_vulkan",
+            res,
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far
+        );
+
+        res
+    }
+
+    /// Creates an off-center perspective projection matrix
+    /// with z mapped to \[-1; 1\], as expected by OpenGL.
+    ///
+    /// `left`, `right`, `bottom` and `top` are the bounds of the frustum on the near plane.
+    /// With symmetric bounds this is equivalent to [`perspective_opengl()`](Self::perspective_opengl()).
+    pub fn frustum_opengl(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let c = (far + near) / (far - near);
+        let d = (-2.0 * far * near) / (far - near);
+        let res = Self::frustum_with_depth(left, right, bottom, top, near, c, d);
+
+        debug_validate!(
+            "Mat4::frustum_opengl",
+            res,
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far
+        );
+
+        res
+    }
+
+    /// Creates a viewport matrix that maps x and y from \[-1; 1\] to `viewport`
+    /// and z to `z_scale * z + z_offset`
+    const fn viewport_with_depth(viewport: Rect, z_scale: f32, z_offset: f32) -> Self {
+        let mut res = Self::IDENTITY;
+
+        res.values[cr(0, 0)] = viewport.width * 0.5;
+        res.values[cr(3, 0)] = viewport.x + viewport.width * 0.5;
+
+        res.values[cr(1, 1)] = viewport.height * 0.5;
+        res.values[cr(3, 1)] = viewport.y + viewport.height * 0.5;
+
+        res.values[cr(2, 2)] = z_scale;
+        res.values[cr(3, 2)] = z_offset;
+
+        res
+    }
+
+    /// Creates a viewport matrix that maps normalized device coordinates
+    /// to window coordinates inside of `viewport`.
+    ///
+    /// z is mapped from \[0; 1\], as produced by Vulkan, to `depth_range` (min, max).
+    pub const fn viewport_vulkan(viewport: Rect, depth_range: Vec2) -> Self {
+        Self::viewport_with_depth(viewport, depth_range.y - depth_range.x, depth_range.x)
+    }
+
+    /// Creates a viewport matrix that maps normalized device coordinates
+    /// to window coordinates inside of `viewport`.
+    ///
+    /// z is mapped from \[-1; 1\], as produced by OpenGL, to `depth_range` (min, max).
+    pub const fn viewport_opengl(viewport: Rect, depth_range: Vec2) -> Self {
+        Self::viewport_with_depth(
+            viewport,
+            (depth_range.y - depth_range.x) * 0.5,
+            (depth_range.y + depth_range.x) * 0.5,
+        )
+    }
+
+    /// Creates a picking matrix, like `gluPickMatrix`.
+    ///
+    /// When multiplied in front of a projection matrix, the region of size `size`
+    /// around `center` in window coordinates of `viewport` will fill the whole viewport.
+    pub fn pick_matrix(center: Vec2, size: Vec2, viewport: Rect) -> Self {
+        let mut res = Self::IDENTITY;
+
+        res.values[cr(0, 0)] = viewport.width / size.x;
+        res.values[cr(3, 0)] = (viewport.width - 2.0 * (center.x - viewport.x)) / size.x;
+
+        res.values[cr(1, 1)] = viewport.height / size.y;
+        res.values[cr(3, 1)] = (viewport.height - 2.0 * (center.y - viewport.y)) / size.y;
+
+        debug_validate!("Mat4::pick_matrix", res, center, size, viewport);
+
+        res
+    }
+
+    /// Projects `world_pos` to window coordinates inside of `viewport`, like `gluProject`.
+    ///
+    /// `self` is expected to be a combined view-projection matrix with z mapped to \[0; 1\], as expected by Vulkan.
+    /// The z component of the result is the window depth in \[0; 1\].
+    pub fn project_vulkan(&self, world_pos: Vec3, viewport: Rect) -> Vec3 {
+        let m = Self::viewport_vulkan(viewport, Vec2::new(0.0, 1.0)) * self;
+        project_point(&m, world_pos)
+    }
+
+    /// Projects `world_pos` to window coordinates inside of `viewport`, like `gluProject`.
+    ///
+    /// `self` is expected to be a combined view-projection matrix with z mapped to \[-1; 1\], as expected by OpenGL.
+    /// The z component of the result is the window depth in \[0; 1\].
+    pub fn project_opengl(&self, world_pos: Vec3, viewport: Rect) -> Vec3 {
+        let m = Self::viewport_opengl(viewport, Vec2::new(0.0, 1.0)) * self;
+        project_point(&m, world_pos)
+    }
+
+    /// Reverses [`project_vulkan()`](Self::project_vulkan()), like `gluUnProject`.
+    ///
+    /// `screen_pos` contains window coordinates inside of `viewport` and the window depth in \[0; 1\].
+    pub fn unproject_vulkan(&self, screen_pos: Vec3, viewport: Rect) -> Vec3 {
+        let m = (Self::viewport_vulkan(viewport, Vec2::new(0.0, 1.0)) * self).inverse();
+        project_point(&m, screen_pos)
+    }
+
+    /// Reverses [`project_opengl()`](Self::project_opengl()), like `gluUnProject`.
+    ///
+    /// `screen_pos` contains window coordinates inside of `viewport` and the window depth in \[0; 1\].
+    pub fn unproject_opengl(&self, screen_pos: Vec3, viewport: Rect) -> Vec3 {
+        let m = (Self::viewport_opengl(viewport, Vec2::new(0.0, 1.0)) * self).inverse();
+        project_point(&m, screen_pos)
+    }
+
+    /// Negates the y axis of clip space when `convention` expects it to point down
+    fn flip_y_for(mut self, convention: NdcConvention) -> Self {
+        if convention.y_down() {
+            for c in 0..4 {
+                self.values[cr(c, 1)] = -self.values[cr(c, 1)];
+            }
+        }
+        self
+    }
+
+    /// Creates a perspective projection matrix for the clip space of `convention`.
+    ///
+    /// Unlike [`perspective_vulkan()`](Self::perspective_vulkan()), this also flips the y axis
+    /// for conventions where it points down, so +y in view space always ends up at the top of the screen.
+    pub fn perspective(
+        fov_rad: f32,
+        near: f32,
+        far: f32,
+        aspect: f32,
+        convention: NdcConvention,
+    ) -> Self {
+        let res = if convention.depth_zero_to_one() {
+            Self::perspective_vulkan(fov_rad, near, far, aspect)
+        } else {
+            Self::perspective_opengl(fov_rad, near, far, aspect)
+        };
+
+        res.flip_y_for(convention)
+    }
+
+    /// Creates an orthographic projection matrix for the clip space of `convention`.
+    ///
+    /// Unlike [`orthographic_vulkan()`](Self::orthographic_vulkan()), this also flips the y axis
+    /// for conventions where it points down, so `top` always ends up at the top of the screen.
+    pub fn orthographic(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+        convention: NdcConvention,
+    ) -> Self {
+        let res = if convention.depth_zero_to_one() {
+            Self::orthographic_vulkan(left, right, bottom, top, near, far)
+        } else {
+            Self::orthographic_opengl(left, right, bottom, top, near, far)
+        };
+
+        res.flip_y_for(convention)
+    }
+
+    /// Creates a perspective projection matrix from a horizontal field of view
+    /// with z mapped to \[0; 1\], as expected by Vulkan.
+    pub fn perspective_horizontal_vulkan(fovx_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+        Self::perspective_vulkan(fovx_to_fovy(fovx_rad, aspect), near, far, aspect)
+    }
+
+    /// Creates a perspective projection matrix from a horizontal field of view
+    /// with z mapped to \[-1; 1\], as expected by OpenGL.
+    pub fn perspective_horizontal_opengl(fovx_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+        Self::perspective_opengl(fovx_to_fovy(fovx_rad, aspect), near, far, aspect)
+    }
+
+    /// Creates a perspective projection matrix for a physical camera
+    /// with z mapped to \[0; 1\], as expected by Vulkan.
+    ///
+    /// `focal_length_mm` and `sensor_size_mm` (width, height) are given in millimeters,
+    /// the aspect ratio is that of the sensor.
+    pub fn perspective_physical_vulkan(
+        focal_length_mm: f32,
+        sensor_size_mm: Vec2,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let fov_rad = 2.0 * (sensor_size_mm.y / (2.0 * focal_length_mm)).atan();
+        let aspect = sensor_size_mm.x / sensor_size_mm.y;
+        Self::perspective_vulkan(fov_rad, near, far, aspect)
+    }
+
+    /// Creates a perspective projection matrix for a physical camera
+    /// with z mapped to \[-1; 1\], as expected by OpenGL.
+    ///
+    /// `focal_length_mm` and `sensor_size_mm` (width, height) are given in millimeters,
+    /// the aspect ratio is that of the sensor.
+    pub fn perspective_physical_opengl(
+        focal_length_mm: f32,
+        sensor_size_mm: Vec2,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let fov_rad = 2.0 * (sensor_size_mm.y / (2.0 * focal_length_mm)).atan();
+        let aspect = sensor_size_mm.x / sensor_size_mm.y;
+        Self::perspective_opengl(fov_rad, near, far, aspect)
+    }
+
+    /// Returns the frustum bounds on the near plane for a physical camera with lens shift
+    fn physical_frustum(
+        focal_length_mm: f32,
+        sensor_size_mm: Vec2,
+        lens_shift: Vec2,
+        near: f32,
+    ) -> (f32, f32, f32, f32) {
+        let half_size = sensor_size_mm * (0.5 * near / focal_length_mm);
+        let offset = lens_shift * (sensor_size_mm.max_element() * near / focal_length_mm);
+
+        (
+            offset.x - half_size.x,
+            offset.x + half_size.x,
+            offset.y - half_size.y,
+            offset.y + half_size.y,
+        )
+    }
+
+    /// Creates a perspective projection matrix for a physical camera with lens shift
+    /// with z mapped to \[0; 1\], as expected by Vulkan.
+    ///
+    /// `lens_shift` offsets the sensor in fractions of its larger dimension on both axes,
+    /// like the `shift_x` and `shift_y` of Blender cameras,
+    /// e.g. a shift of 0.5 on a landscape sensor moves the image by half its width.
+    /// Per-axis shifts in fractions of the sensor's width and height (like Unity's lens shift)
+    /// can be converted with `shift * sensor_size_mm / sensor_size_mm.max_element()`.
+    /// See [`perspective_physical_vulkan()`](Self::perspective_physical_vulkan()) for the other parameters.
+    pub fn perspective_physical_shifted_vulkan(
+        focal_length_mm: f32,
+        sensor_size_mm: Vec2,
+        lens_shift: Vec2,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let (left, right, bottom, top) =
+            Self::physical_frustum(focal_length_mm, sensor_size_mm, lens_shift, near);
+        Self::frustum_vulkan(left, right, bottom, top, near, far)
+    }
+
+    /// Creates a perspective projection matrix for a physical camera with lens shift
+    /// with z mapped to \[-1; 1\], as expected by OpenGL.
+    ///
+    /// See [`perspective_physical_shifted_vulkan()`](Self::perspective_physical_shifted_vulkan()) for the parameters.
+    pub fn perspective_physical_shifted_opengl(
+        focal_length_mm: f32,
+        sensor_size_mm: Vec2,
+        lens_shift: Vec2,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let (left, right, bottom, top) =
+            Self::physical_frustum(focal_length_mm, sensor_size_mm, lens_shift, near);
+        Self::frustum_opengl(left, right, bottom, top, near, far)
+    }
+
+    /// Creates an inverse perspective matrix
+    /// with z mapped to \[0; 1\], as expected by Vulkan.
+    ///
+    /// This matrix can be used to reconstruct the world position from a uv/depth pair in a shader.
+    /// This pseudo code can be used:
+    /// ```GLSL
+    /// vec4 clipPos = vec4(uv.xy, depth, 1.0);
+    /// vec4 worldPos = invProjection * clipPos;
+    /// worldPos /= worldPos.w;
+    /// ```
+    pub fn inverse_perspective_vulkan(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+        let c = far / (far - near);
+        let d = (-far * near) / (far - near);
+        let res = Self::inverse_perspective_with_depth(fov_rad, aspect, c, d);
+
+        debug_validate!(
+            "Mat4::inverse_perspective_vulkan",
+            res,
+            fov_rad,
+            near,
+            far,
+            aspect
+        );
+
+        res
+    }
+
+    /// Creates an inverse perspective matrix
+    /// with z mapped to \[-1; 1\], as expected by OpenGL.
+    ///
+    /// This matrix can be used to reconstruct the world position from a uv/depth pair in a shader.
+    /// This pseudo code can be used:
+    /// ```GLSL
+    /// vec4 clipPos = vec4(uv.xy, depth * 2.0 - 1.0, 1.0);
+    /// vec4 worldPos = invProjection * clipPos;
+    /// worldPos /= worldPos.w;
+    /// ```
+    pub fn inverse_perspective_opengl(fov_rad: f32, near: f32, far: f32, aspect: f32) -> Self {
+        let c = (far + near) / (far - near);
+        let d = (-2.0 * far * near) / (far - near);
+        let res = Self::inverse_perspective_with_depth(fov_rad, aspect, c, d);
+
+        debug_validate!(
+            "Mat4::inverse_perspective_opengl",
+            res,
+            fov_rad,
+            near,
+            far,
+            aspect
+        );
+
+        res
+    }
+
+    /// Creates the inverse of [`perspective_vulkan_reversed_z()`](Self::perspective_vulkan_reversed_z()).
+    ///
+    /// It can be used the same way as [`inverse_perspective_vulkan()`](Self::inverse_perspective_vulkan())
+    pub fn inverse_perspective_vulkan_reversed_z(
+        fov_rad: f32,
+        near: f32,
+        far: f32,
+        aspect: f32,
+    ) -> Self {
+        let c = -near / (far - near);
+        let d = (far * near) / (far - near);
+        let res = Self::inverse_perspective_with_depth(fov_rad, aspect, c, d);
+
+        debug_validate!(
+            "Mat4::inverse_perspective_vulkan_reversed_z",
+            res,
+            fov_rad,
+            near,
+            far,
+            aspect
+        );
+
+        res
+    }
+
+    /// Creates the inverse of [`perspective_opengl_reversed_z()`](Self::perspective_opengl_reversed_z()).
+    ///
+    /// It can be used the same way as [`inverse_perspective_opengl()`](Self::inverse_perspective_opengl())
+    pub fn inverse_perspective_opengl_reversed_z(
+        fov_rad: f32,
+        near: f32,
+        far: f32,
+        aspect: f32,
+    ) -> Self {
+        let c = -(far + near) / (far - near);
+        let d = (2.0 * far * near) / (far - near);
+        let res = Self::inverse_perspective_with_depth(fov_rad, aspect, c, d);
+
+        debug_validate!(
+            "Mat4::inverse_perspective_opengl_reversed_z",
+            res,
+            fov_rad,
+            near,
+            far,
+            aspect
+        );
+
+        res
+    }
+
+    /// Returns the vertical field of view in radians of the perspective projection matrix `self`
+    pub fn perspective_fov(&self) -> f32 {
+        2.0 * (1.0 / self.values[cr(1, 1)]).atan()
+    }
+
+    /// Returns the aspect ratio (width / height) of the perspective projection matrix `self`
+    pub fn perspective_aspect(&self) -> f32 {
+        self.values[cr(1, 1)] / self.values[cr(0, 0)]
+    }
+
+    /// Returns the near and far plane of the perspective projection matrix `self`,
+    /// which has been created by [`perspective_vulkan()`](Self::perspective_vulkan())
+    pub fn perspective_near_far_vulkan(&self) -> (f32, f32) {
+        let c = self.values[cr(2, 2)];
+        let d = self.values[cr(3, 2)];
+        (-d / c, -d / (c - 1.0))
+    }
+
+    /// Returns the near and far plane of the perspective projection matrix `self`,
+    /// which has been created by [`perspective_opengl()`](Self::perspective_opengl())
+    pub fn perspective_near_far_opengl(&self) -> (f32, f32) {
+        let c = self.values[cr(2, 2)];
+        let d = self.values[cr(3, 2)];
+        (-d / (c + 1.0), -d / (c - 1.0))
+    }
+
+    /// Returns the left and right plane of the orthographic projection matrix `self`
+    pub fn orthographic_left_right(&self) -> (f32, f32) {
+        let a = self.values[cr(0, 0)];
+        let b = self.values[cr(3, 0)];
+        ((-1.0 - b) / a, (1.0 - b) / a)
+    }
+
+    /// Returns the bottom and top plane of the orthographic projection matrix `self`
+    pub fn orthographic_bottom_top(&self) -> (f32, f32) {
+        let c = self.values[cr(1, 1)];
+        let d = self.values[cr(3, 1)];
+        ((-1.0 - d) / c, (1.0 - d) / c)
+    }
+
+    /// Returns the near and far plane of the orthographic projection matrix `self`,
+    /// which has been created by [`orthographic_vulkan()`](Self::orthographic_vulkan())
+    pub fn orthographic_near_far_vulkan(&self) -> (f32, f32) {
+        let e = self.values[cr(2, 2)];
+        let f = self.values[cr(3, 2)];
+        (-f / e, (1.0 - f) / e)
+    }
+
+    /// Returns the near and far plane of the orthographic projection matrix `self`,
+    /// which has been created by [`orthographic_opengl()`](Self::orthographic_opengl())
+    pub fn orthographic_near_far_opengl(&self) -> (f32, f32) {
+        let e = self.values[cr(2, 2)];
+        let f = self.values[cr(3, 2)];
+        ((-1.0 - f) / e, (1.0 - f) / e)
+    }
+
+    /// Creates the 2D projective transformation (homography) mapping each point in `src`
+    /// onto the point with the same index in `dst`.
+    ///
+    /// The 3x3 homography is embedded into x, y and w of the resulting matrix, z is passed through.
+    /// A point `p` is mapped by multiplying `Vec4::new(p.x, p.y, 0.0, 1.0)` with the matrix
+    /// and dividing x and y by w.
+    ///
+    /// Returns `None` if three of the points in `src` or `dst` are collinear.
+    pub fn homography(src: [Vec2; 4], dst: [Vec2; 4]) -> Option<Self> {
+        // Each correspondence yields two linear equations in the eight unknowns
+        // h11, h12, h13, h21, h22, h23, h31, h32 (h33 is fixed to 1)
+        let mut m = [[0.0f32; 9]; 8];
+        for i in 0..4 {
+            let Vec2 { x, y } = src[i];
+            let Vec2 { x: u, y: v } = dst[i];
+
+            m[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -x * u, -y * u, u];
+            m[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -x * v, -y * v, v];
+        }
+
+        // Gauss-Jordan elimination with partial pivoting
+        for c in 0..8 {
+            let pivot = (c..8).max_by(|&a, &b| m[a][c].abs().total_cmp(&m[b][c].abs()))?;
+            if m[pivot][c].abs() < 1e-9 {
+                return None;
+            }
+            m.swap(c, pivot);
+
+            let p = m[c][c];
+            for v in &mut m[c][c..] {
+                *v /= p;
+            }
+
+            let pivot_row = m[c];
+            for (r, row) in m.iter_mut().enumerate() {
+                if r != c {
+                    let f = row[c];
+                    for (v, p) in row[c..].iter_mut().zip(&pivot_row[c..]) {
+                        *v -= f * p;
+                    }
+                }
+            }
+        }
+
+        let h = |i: usize| m[i][8];
+
+        let mut res = Self::IDENTITY;
+
+        res.values[cr(0, 0)] = h(0);
+        res.values[cr(1, 0)] = h(1);
+        res.values[cr(3, 0)] = h(2);
+
+        res.values[cr(0, 1)] = h(3);
+        res.values[cr(1, 1)] = h(4);
+        res.values[cr(3, 1)] = h(5);
+
+        res.values[cr(0, 3)] = h(6);
+        res.values[cr(1, 3)] = h(7);
+
+        Some(res)
+    }
+
+    /// Returns `true` if all values of `self` are neither infinite nor NaN
+    pub fn is_finite(&self) -> bool {
+        self.values.iter().all(|v| v.is_finite())
+    }
+
+    /// Returns the upper three values of the column with index `column`
+    fn axis(&self, column: usize) -> Vec3 {
+        Vec3::new(
+            self.values[cr(column, 0)],
+            self.values[cr(column, 1)],
+            self.values[cr(column, 2)],
+        )
+    }
+
+    /// Returns the translation of `self`, i.e. the `t` passed to [`local_to_world()`](Self::local_to_world())
+    pub fn get_translation(&self) -> Vec3 {
+        self.axis(3)
+    }
+
+    /// Returns the rotation of `self`, i.e. the `r` passed to [`local_to_world()`](Self::local_to_world()).
+    ///
+    /// If `self` mirrors, the mirroring is attributed to the x axis, see [`get_scale()`](Self::get_scale()).
+    pub fn get_rotation(&self) -> Quaternion {
+        let sign = self.determinant().signum();
+        Quaternion::from_axes(
+            self.axis(0).normalized() * sign,
+            self.axis(1).normalized(),
+            self.axis(2).normalized(),
+        )
+    }
+
+    /// Returns the scale of `self`, i.e. the `s` passed to [`local_to_world()`](Self::local_to_world()).
+    ///
+    /// A mirroring transformation can not be distinguished from a rotation combined with
+    /// a negative scale on any one axis, so the x scale is negated in that case.
+    pub fn get_scale(&self) -> Vec3 {
+        let sign = self.determinant().signum();
+        Vec3::new(
+            self.axis(0).magnitude() * sign,
+            self.axis(1).magnitude(),
+            self.axis(2).magnitude(),
+        )
+    }
+
+    /// Interpolates between `self` and `b` by decomposing both into translation, rotation and scale.
+    ///
+    /// Translation and scale are interpolated linearly, the rotation is spherically interpolated.
+    /// Unlike [`lerp()`](Lerp::lerp()), this keeps the result a valid transformation
+    /// even for very different rotations. Both matrices are expected to be created by
+    /// [`local_to_world()`](Self::local_to_world()) or similar. `t` is clamped to \[0; 1\].
+    pub fn interpolate_trs(&self, b: Mat4, t: f32) -> Mat4 {
+        let translation = self.get_translation().lerp(b.get_translation(), t);
+        let rotation = Slerp(self.get_rotation())
+            .lerp(Slerp(b.get_rotation()), t)
+            .0;
+        let scale = self.get_scale().lerp(b.get_scale(), t);
+
+        Self::local_to_world(translation, rotation, scale)
+    }
+
+    /// Returns whether the determinant of `self` is further than `epsilon` from zero
+    pub fn is_invertible(&self, epsilon: f32) -> bool {
+        self.determinant().abs() > epsilon
+    }
+
+    /// Returns whether all values of `self` are within `epsilon` of the identity matrix
+    pub fn is_identity(&self, epsilon: f32) -> bool {
+        self.values
+            .iter()
+            .zip(Self::IDENTITY.values)
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// Returns whether the last row of `self` is within `epsilon` of `(0, 0, 0, 1)`,
+    /// i.e. whether `self` is an affine transformation without any projective part
+    pub fn is_affine(&self, epsilon: f32) -> bool {
+        let row = self.get_row(3);
+        row.x.abs() <= epsilon
+            && row.y.abs() <= epsilon
+            && row.z.abs() <= epsilon
+            && (row.w - 1.0).abs() <= epsilon
+    }
+
+    /// Returns whether the upper left 3x3 block of `self` is orthogonal within `epsilon`,
+    /// i.e. whether its columns are perpendicular unit vectors.
+    ///
+    /// This is the case for rotations and reflections without scale or skew.
+    /// The translation is not checked.
+    pub fn is_orthogonal(&self, epsilon: f32) -> bool {
+        (0..3).all(|a| {
+            (0..3).all(|b| {
+                let expected = if a == b { 1.0 } else { 0.0 };
+                (self.axis(a).dot(self.axis(b)) - expected).abs() <= epsilon
+            })
+        })
+    }
+
+    /// Returns a value indexed by `column` and `row`
+    pub const fn get(&self, column: usize, row: usize) -> f32 {
+        self.values[cr(column, row)]
+    }
+
+    /// Sets the value indexed by `column` and `row` to `val`
+    pub fn set(&mut self, column: usize, row: usize, val: f32) {
+        self.values[cr(column, row)] = val;
+    }
+
+    /// Returns the diagonal of `self`
+    pub const fn diagonal(&self) -> Vec4 {
+        Vec4::new(
+            self.values[cr(0, 0)],
+            self.values[cr(1, 1)],
+            self.values[cr(2, 2)],
+            self.values[cr(3, 3)],
+        )
+    }
+
+    /// Returns the values of `self` in column-major order as a byte array in little-endian byte order.
+    ///
+    /// The byte layout does not depend on the `mat-row-major` feature.
+    pub fn to_le_bytes(&self) -> [u8; 4 * 4 * 4] {
+        self.encode_bytes(f32::to_le_bytes)
+    }
+
+    /// Returns the values of `self` in column-major order as a byte array in big-endian byte order.
+    ///
+    /// The byte layout does not depend on the `mat-row-major` feature.
+    pub fn to_be_bytes(&self) -> [u8; 4 * 4 * 4] {
+        self.encode_bytes(f32::to_be_bytes)
+    }
+
+    /// Creates a matrix from column-major values stored as a byte array in little-endian byte order
+    pub fn from_le_bytes(bytes: [u8; 4 * 4 * 4]) -> Self {
+        Self::decode_bytes(bytes, f32::from_le_bytes)
+    }
+
+    /// Creates a matrix from column-major values stored as a byte array in big-endian byte order
+    pub fn from_be_bytes(bytes: [u8; 4 * 4 * 4]) -> Self {
+        Self::decode_bytes(bytes, f32::from_be_bytes)
+    }
+
+    fn encode_bytes(&self, conv: fn(f32) -> [u8; 4]) -> [u8; 4 * 4 * 4] {
+        let mut res = [0; 4 * 4 * 4];
+        let mut chunks = res.chunks_exact_mut(4);
+
+        for c in 0..4 {
+            for r in 0..4 {
+                chunks
+                    .next()
+                    .unwrap()
+                    .copy_from_slice(&conv(self.values[cr(c, r)]));
+            }
+        }
+
+        res
+    }
+
+    fn decode_bytes(bytes: [u8; 4 * 4 * 4], conv: fn([u8; 4]) -> f32) -> Self {
+        let mut res = Self::IDENTITY;
+        let mut chunks = bytes.chunks_exact(4);
+
+        for c in 0..4 {
+            for r in 0..4 {
+                res.values[cr(c, r)] = conv(chunks.next().unwrap().try_into().unwrap());
+            }
+        }
+
+        res
+    }
+
+    /// Returns the column with index `column`
+    pub const fn get_column(&self, column: usize) -> Vec4 {
+        Vec4::new(
+            self.values[cr(column, 0)],
+            self.values[cr(column, 1)],
+            self.values[cr(column, 2)],
+            self.values[cr(column, 3)],
+        )
+    }
+
+    /// Returns the row with index `row`
+    pub const fn get_row(&self, row: usize) -> Vec4 {
+        Vec4::new(
+            self.values[cr(0, row)],
+            self.values[cr(1, row)],
+            self.values[cr(2, row)],
+            self.values[cr(3, row)],
+        )
+    }
+
+    /// Sets the column with index `column` to `val`
+    pub fn set_column(&mut self, column: usize, val: Vec4) {
+        self.values[cr(column, 0)] = val.x;
+        self.values[cr(column, 1)] = val.y;
+        self.values[cr(column, 2)] = val.z;
+        self.values[cr(column, 3)] = val.w;
+    }
+
+    /// Sets the row with index `row` to `val`
+    pub fn set_row(&mut self, row: usize, val: Vec4) {
+        self.values[cr(0, row)] = val.x;
+        self.values[cr(1, row)] = val.y;
+        self.values[cr(2, row)] = val.z;
+        self.values[cr(3, row)] = val.w;
+    }
+
+    /// Returns the cofactor of the value at `column` and `row`, i.e. the signed determinant of
+    /// the 3x3 matrix that remains after removing `column` and `row`
+    pub fn cofactor(&self, column: usize, row: usize) -> f32 {
+        let mut cols = [0; 3];
+        let mut rows = [0; 3];
+        for (i, c) in (0..4).filter(|&c| c != column).enumerate() {
+            cols[i] = c;
+        }
+        for (i, r) in (0..4).filter(|&r| r != row).enumerate() {
+            rows[i] = r;
+        }
+
+        let m = |c: usize, r: usize| self.values[cr(cols[c], rows[r])];
+        let minor = m(0, 0) * (m(1, 1) * m(2, 2) - m(2, 1) * m(1, 2))
+            - m(1, 0) * (m(0, 1) * m(2, 2) - m(2, 1) * m(0, 2))
+            + m(2, 0) * (m(0, 1) * m(1, 2) - m(1, 1) * m(0, 2));
+
+        if (column + row) & 1 == 0 {
+            minor
+        } else {
+            -minor
+        }
+    }
+
+    /// Returns the determinant of `self`
+    pub fn determinant(&self) -> f32 {
+        (0..4)
+            .map(|c| self.values[cr(c, 0)] * self.cofactor(c, 0))
+            .sum()
+    }
+
+    /// Returns the matrix of all cofactors of `self`, see [`cofactor()`](Self::cofactor())
+    pub fn cofactor_matrix(&self) -> Mat4 {
+        self.scaled_adjugate(1.0).transposed()
+    }
+
+    /// Returns the adjugate of `self`, i.e. its transposed cofactor matrix.
+    ///
+    /// For invertible matrices, this equals the inverse multiplied by the determinant.
+    pub fn adjugate(&self) -> Mat4 {
+        self.scaled_adjugate(1.0)
+    }
+
+    /// Returns the trace of `self`, i.e. the sum of its diagonal values
+    pub fn trace(&self) -> f32 {
+        (0..4).map(|i| self.values[cr(i, i)]).sum()
+    }
+
+    /// Returns the transposed cofactor matrix of `self` scaled by `inv_det`
+    fn scaled_adjugate(&self, inv_det: f32) -> Mat4 {
+        let mut res = Mat4::IDENTITY;
+
+        for c in 0..4 {
+            for r in 0..4 {
+                res.values[cr(c, r)] = self.cofactor(r, c) * inv_det;
+            }
+        }
+
+        res
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// If `self` is not invertible, the result will contain non-finite values.
+    /// Use [`try_inverse()`](Self::try_inverse()) if that can happen.
+    #[must_use]
+    pub fn inverse(&self) -> Mat4 {
+        let res = self.scaled_adjugate(1.0 / self.determinant());
+        debug_validate!("Mat4::inverse", res, self);
+        res
+    }
+
+    /// Returns the inverse of `self`,
+    /// or `None` if `self` is singular or nearly singular, i.e. the absolute value of its determinant
+    /// is not greater than `epsilon`.
+    ///
+    /// The determinant scales with the values of the matrix, e.g. by `s^4` for a uniform scale `s`,
+    /// so `epsilon` should be chosen relative to the expected magnitude of `self`.
+    ///
+    /// Uses the same criterion as [`is_invertible()`](Self::is_invertible()).
+    pub fn try_inverse(&self, epsilon: f32) -> Option<Mat4> {
+        let det = self.determinant();
+        if det.abs() <= epsilon || !det.is_finite() {
+            return None;
+        }
+
+        let res = self.scaled_adjugate(1.0 / det);
+        res.is_finite().then_some(res)
+    }
+
+    /// Returns the inverse of `self`, assuming it only consists of
+    /// translation, rotation and (possibly non-uniform) scale, e.g. as created by
+    /// [`local_to_world()`](Self::local_to_world()).
+    ///
+    /// This is much cheaper than [`inverse()`](Self::inverse()),
+    /// but the result is wrong for skewed or projective matrices.
+    #[must_use]
+    pub fn inverse_affine(&self) -> Mat4 {
+        let mut res = Mat4::IDENTITY;
+
+        // the columns of the rotation-scale block are orthogonal,
+        // so its inverse consists of the columns divided by their squared lengths as rows
+        for c in 0..3 {
+            let axis = self.axis(c);
+            let axis = axis / axis.sqr_magnitude();
+            res.values[cr(0, c)] = axis.x;
+            res.values[cr(1, c)] = axis.y;
+            res.values[cr(2, c)] = axis.z;
+        }
+
+        let t = -(res * self.axis(3));
+        res.values[cr(3, 0)] = t.x;
+        res.values[cr(3, 1)] = t.y;
+        res.values[cr(3, 2)] = t.z;
+
+        debug_validate!("Mat4::inverse_affine", res, self);
+        res
+    }
+
+    /// Returns the inverse-transpose of the upper left 3x3 block of `self`.
+    ///
+    /// Normals have to be transformed by this matrix instead of `self`
+    /// to stay perpendicular to surfaces under non-uniform scale.
+    /// The transformed normals have to be renormalized afterwards.
+    pub fn normal_matrix(&self) -> Mat3 {
+        self.to_mat3().inverse().transposed()
+    }
+
+    /// Returns the upper left 3x3 block of `self`
+    pub fn to_mat3(&self) -> Mat3 {
+        let mut res = Mat3::IDENTITY;
+        for c in 0..3 {
+            for r in 0..3 {
+                res.set(c, r, self.values[cr(c, r)]);
+            }
+        }
+        res
+    }
+
+    /// Creates a matrix with `m` as the upper left 3x3 block and the rest taken from the identity matrix
+    pub fn from_mat3(m: Mat3) -> Self {
+        let mut res = Self::IDENTITY;
+        for c in 0..3 {
+            for r in 0..3 {
+                res.values[cr(c, r)] = m.get(c, r);
+            }
+        }
+        res
+    }
+
+    /// Returns a copy of `self` with the translation and projective parts removed,
+    /// keeping only rotation, scale and skew.
+    ///
+    /// This turns a view matrix into one suitable for rendering a skybox.
+    #[must_use]
+    pub fn rotation_scale_part(&self) -> Mat4 {
+        Self::from_mat3(self.to_mat3())
+    }
+
+    /// Orthonormalizes the upper left 3x3 block of `self` in place using Gram-Schmidt,
+    /// removing any scale and skew.
+    ///
+    /// The x axis keeps its direction, the y axis is made perpendicular to it
+    /// and the z axis perpendicular to both. The translation is left untouched.
+    pub fn orthonormalize(&mut self) -> &mut Self {
+        let x = self.axis(0).normalized();
+        let y = self.axis(1);
+        let y = (y - x * x.dot(y)).normalized();
+        let z = self.axis(2);
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalized();
+
+        for (c, axis) in [x, y, z].into_iter().enumerate() {
+            self.values[cr(c, 0)] = axis.x;
+            self.values[cr(c, 1)] = axis.y;
+            self.values[cr(c, 2)] = axis.z;
+        }
+
+        self
+    }
+
+    /// Returns an orthonormalized copy of `self`, see [`orthonormalize()`](Self::orthonormalize())
+    #[must_use]
+    pub fn orthonormalized(&self) -> Self {
+        *self.clone().orthonormalize()
+    }
+
+    /// Returns a transposed copy of `self`.
+    #[must_use]
+    pub const fn transposed(&self) -> Mat4 {
+        let mut res = Mat4::IDENTITY;
+
+        let mut c = 0;
+        while c < 4 {
+            let mut r = 0;
+            while r < 4 {
+                res.values[cr(r, c)] = self.values[cr(c, r)];
+                r += 1;
+            }
+            c += 1;
+        }
+
+        res
+    }
+
+    /// Returns the matrix product `self * b`.
+    ///
+    /// This is the same as the `*` operator, but can be used in const contexts.
+    #[must_use]
+    pub const fn mul_mat4(&self, b: &Mat4) -> Mat4 {
+        let mut res = Mat4::IDENTITY;
+
+        let mut r = 0;
+        while r < 4 {
+            let mut c = 0;
+            while c < 4 {
+                res.values[cr(c, r)] = self.values[cr(0, r)] * b.values[cr(c, 0)]
+                    + self.values[cr(1, r)] * b.values[cr(c, 1)]
+                    + self.values[cr(2, r)] * b.values[cr(c, 2)]
+                    + self.values[cr(3, r)] * b.values[cr(c, 3)];
+                c += 1;
+            }
+            r += 1;
+        }
+
+        res
+    }
+
+    /// Creates a matrix from `values` in column-major order, regardless of the storage order
+    pub const fn from_col_major(values: [f32; 16]) -> Self {
+        let mut res = Self::IDENTITY;
+
+        let mut i = 0;
+        while i < 16 {
+            res.values[cr(i / 4, i % 4)] = values[i];
+            i += 1;
+        }
+
+        res
+    }
+
+    /// Creates a matrix from `values` in row-major order, regardless of the storage order
+    pub const fn from_row_major(values: [f32; 16]) -> Self {
+        let mut res = Self::IDENTITY;
+
+        let mut i = 0;
+        while i < 16 {
+            res.values[cr(i % 4, i / 4)] = values[i];
+            i += 1;
+        }
+
+        res
+    }
+
+    /// Returns the values of `self` in column-major order, regardless of the storage order
+    pub const fn to_col_major_array(&self) -> [f32; 16] {
+        let mut res = [0.0; 16];
+
+        let mut i = 0;
+        while i < 16 {
+            res[i] = self.values[cr(i / 4, i % 4)];
+            i += 1;
+        }
+
+        res
+    }
+
+    /// Returns the values of `self` in row-major order, regardless of the storage order
+    pub const fn to_row_major_array(&self) -> [f32; 16] {
+        let mut res = [0.0; 16];
+
+        let mut i = 0;
+        while i < 16 {
+            res[i] = self.values[cr(i % 4, i / 4)];
+            i += 1;
+        }
+
+        res
+    }
+
+    /// Returns the columns of `self` as arrays, regardless of the storage order
+    pub const fn to_cols_array_2d(&self) -> [[f32; 4]; 4] {
+        let mut res = [[0.0; 4]; 4];
+
+        let mut c = 0;
+        while c < 4 {
+            let mut r = 0;
+            while r < 4 {
+                res[c][r] = self.values[cr(c, r)];
+                r += 1;
+            }
+            c += 1;
+        }
+
+        res
+    }
+
+    /// Returns the rows of `self` as arrays, regardless of the storage order
+    pub const fn to_rows_array_2d(&self) -> [[f32; 4]; 4] {
+        let mut res = [[0.0; 4]; 4];
+
+        let mut r = 0;
+        while r < 4 {
+            let mut c = 0;
+            while c < 4 {
+                res[r][c] = self.values[cr(c, r)];
+                c += 1;
+            }
+            r += 1;
+        }
+
+        res
+    }
+
+    /// Returns the underlying values as a slice
+    pub fn as_slice(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Returns the underlying values as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.values
+    }
+
+    /// Returns the underlying values as a pointer with no size information
+    pub fn as_ptr(&self) -> *const f32 {
+        self.values.as_ptr()
+    }
+
+    /// Returns the underlying values as a mutable pointer with no size information
+    pub fn as_mut_ptr(&mut self) -> *mut f32 {
+        self.values.as_mut_ptr()
+    }
+
+    /// Returns an iterator over the values in storage order, see [`as_slice()`](Self::as_slice())
+    pub fn iter(&self) -> std::array::IntoIter<f32, 16> {
+        self.values.into_iter()
+    }
+}
+
+/// Interpolates componentwise.
+///
+/// The result is only meaningful for matrices that are close to each other.
+impl Lerp for Mat4 {
+    fn lerp_unclamped(&self, b: Self, t: f32) -> Self {
+        let mut res = *self;
+
+        for (r, b) in res.values.iter_mut().zip(b.values) {
+            *r += (b - *r) * t;
+        }
+
+        res
+    }
+}
+
+/// Converts a horizontal field of view to the vertical field of view
+/// of a viewport with the aspect ratio `aspect` (width / height).
+pub fn fovx_to_fovy(fovx_rad: f32, aspect: f32) -> f32 {
+    2.0 * ((fovx_rad * 0.5).tan() / aspect).atan()
+}
+
+/// Converts a vertical field of view to the horizontal field of view
+/// of a viewport with the aspect ratio `aspect` (width / height).
+pub fn fovy_to_fovx(fovy_rad: f32, aspect: f32) -> f32 {
+    2.0 * ((fovy_rad * 0.5).tan() * aspect).atan()
+}
+
+/// Transforms the point `p` by `m`, including the perspective divide
+fn project_point(m: &Mat4, p: Vec3) -> Vec3 {
+    (m * p.extend(1.0)).to_vec3_homogeneous()
+}
+
+impl_op_ex!(+= |a: &mut Mat4, b: &Mat4| { for (v, b) in a.values.iter_mut().zip(b.values) { *v += b; } });
+impl_op_ex!(-= |a: &mut Mat4, b: &Mat4| { for (v, b) in a.values.iter_mut().zip(b.values) { *v -= b; } });
+// multiplies `b` from the right, i.e. `a = a * b`
+impl_op_ex!(*= |a: &mut Mat4, b: &Mat4| { *a = *a * b; });
+
+impl_op_ex!(*= |a: &mut Mat4, b: &f32| { for v in &mut a.values { *v *= b; } });
+impl_op_ex!(/= |a: &mut Mat4, b: &f32| { for v in &mut a.values { *v /= b; } debug_validate!("Mat4 /= f32", a, b); });
+
+impl_op_ex!(+ |a: &Mat4, b: &Mat4| -> Mat4 { let mut res = *a; res += b; res });
+impl_op_ex!(-|a: &Mat4, b: &Mat4| -> Mat4 {
+    let mut res = *a;
+    res -= b;
+    res
+});
+
+impl_op_ex_commutative!(*|a: &Mat4, b: &f32| -> Mat4 {
+    let mut res = *a;
+    res *= b;
+    res
+});
+impl_op_ex!(/ |a: &Mat4, b: &f32| -> Mat4 { let mut res = *a; res /= b; res });
+
+impl_op_ex!(*|a: &Mat4, b: &Mat4| -> Mat4 {
+    #[cfg(all(feature = "simd", not(feature = "mat-row-major")))]
+    let res = Mat4 {
+        values: crate::simd::mul_lanes(&a.values, &b.values),
+    };
+    #[cfg(all(feature = "simd", feature = "mat-row-major"))]
+    let res = Mat4 {
+        values: crate::simd::mul_lanes(&b.values, &a.values),
+    };
+
+    #[cfg(not(feature = "simd"))]
+    let res = a.mul_mat4(b);
+
+    debug_validate!("Mat4 * Mat4", res, a, b);
+    res
+});
+
+impl_op_ex!(*|a: &Mat4, b: &Vec4| -> Vec4 {
+    #[cfg(all(feature = "simd", not(feature = "mat-row-major")))]
+    let res = Vec4::from(crate::simd::combine_lanes(&a.values, [b.x, b.y, b.z, b.w]));
+
+    #[cfg(not(all(feature = "simd", not(feature = "mat-row-major"))))]
+    let res = Vec4 {
+        x: a.values[cr(0, 0)] * b.x
+            + a.values[cr(1, 0)] * b.y
+            + a.values[cr(2, 0)] * b.z
+            + a.values[cr(3, 0)] * b.w,
+        y: a.values[cr(0, 1)] * b.x
+            + a.values[cr(1, 1)] * b.y
+            + a.values[cr(2, 1)] * b.z
+            + a.values[cr(3, 1)] * b.w,
+        z: a.values[cr(0, 2)] * b.x
+            + a.values[cr(1, 2)] * b.y
+            + a.values[cr(2, 2)] * b.z
+            + a.values[cr(3, 2)] * b.w,
+        w: a.values[cr(0, 3)] * b.x
+            + a.values[cr(1, 3)] * b.y
+            + a.values[cr(2, 3)] * b.z
+            + a.values[cr(3, 3)] * b.w,
+    };
+    debug_validate!("Mat4 * Vec4", res, a, b);
+    res
+});
+
+impl_op_ex!(*|a: &Mat4, b: &Vec3| -> Vec3 {
+    let res = Vec3 {
+        x: a.values[cr(0, 0)] * b.x + a.values[cr(1, 0)] * b.y + a.values[cr(2, 0)] * b.z,
+        y: a.values[cr(0, 1)] * b.x + a.values[cr(1, 1)] * b.y + a.values[cr(2, 1)] * b.z,
+        z: a.values[cr(0, 2)] * b.x + a.values[cr(1, 2)] * b.y + a.values[cr(2, 2)] * b.z,
+    };
+    debug_validate!("Mat4 * Vec3", res, a, b);
+    res
+});
+
+impl From<[f32; 16]> for Mat4 {
+    fn from(d: [f32; 16]) -> Self {
+        Self { values: d }
+    }
+}
+
+impl From<[[f32; 4]; 4]> for Mat4 {
+    fn from(d: [[f32; 4]; 4]) -> Self {
+        Self {
+            values: [
+                d[0][0], d[0][1], d[0][2], d[0][3], d[1][0], d[1][1], d[1][2], d[1][3], d[2][0],
+                d[2][1], d[2][2], d[2][3], d[3][0], d[3][1], d[3][2], d[3][3],
+            ],
+        }
+    }
+}
+
+/// Returns the values in storage order, see [`Mat4::as_slice()`]
+impl From<Mat4> for [f32; 16] {
+    fn from(m: Mat4) -> Self {
+        m.values
+    }
+}
+
+/// Returns the values in storage order,
+/// i.e. an array of columns by default and an array of rows with feature `mat-row-major`.
+///
+/// Use [`Mat4::to_cols_array_2d()`] or [`Mat4::to_rows_array_2d()`]
+/// to get a layout that does not depend on the storage order.
+impl From<Mat4> for [[f32; 4]; 4] {
+    fn from(m: Mat4) -> Self {
+        let v = m.values;
+        [
+            [v[0], v[1], v[2], v[3]],
+            [v[4], v[5], v[6], v[7]],
+            [v[8], v[9], v[10], v[11]],
+            [v[12], v[13], v[14], v[15]],
+        ]
+    }
+}
+
+/// Iterates over the values in storage order, see [`Mat4::as_slice()`]
+impl IntoIterator for Mat4 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 16>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+/// Iterates over the values in storage order, see [`Mat4::as_slice()`]
+impl IntoIterator for &Mat4 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 16>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<Quaternion> for Mat4 {
+    fn from(q: Quaternion) -> Self {
+        Self::rotate(q)
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for Mat4 {
+    type Output = f32;
+
+    fn index(&self, (c, r): (usize, usize)) -> &Self::Output {
         &self.values[cr(c, r)]
     }
 }
@@ -445,3 +2033,867 @@ impl std::ops::IndexMut<(usize, usize)> for Mat4 {
         &mut self.values[cr(c, r)]
     }
 }
+
+// Index<usize> reinterprets four consecutive values as a Vec4
+const _: () = assert!(
+    std::mem::size_of::<Vec4>() == 4 * std::mem::size_of::<f32>()
+        && std::mem::align_of::<Vec4>() == std::mem::align_of::<f32>()
+);
+
+/// Returns the four values at index `i` in storage order, see [`Mat4::as_slice()`].
+///
+/// By default, this is the column `i`, like `m[i]` in GLSL.
+/// With feature `mat-row-major`, it is the row `i` instead, as the columns are not contiguous in memory.
+/// Use [`Mat4::get_column()`] and [`Mat4::set_column()`] for column access independent of the storage order.
+impl std::ops::Index<usize> for Mat4 {
+    type Output = Vec4;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        let values: &[f32; 4] = self.values[i * 4..i * 4 + 4].try_into().unwrap();
+        // SAFETY: Vec4 is repr(C) and consists of exactly four f32 values, see the assertion above
+        unsafe { &*(values as *const [f32; 4] as *const Vec4) }
+    }
+}
+
+/// Returns the four values at index `i` in storage order, see [`Mat4::as_slice()`].
+///
+/// By default, this is the column `i`, like `m[i]` in GLSL.
+/// With feature `mat-row-major`, it is the row `i` instead, as the columns are not contiguous in memory.
+/// Use [`Mat4::get_column()`] and [`Mat4::set_column()`] for column access independent of the storage order.
+impl std::ops::IndexMut<usize> for Mat4 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        let values: &mut [f32; 4] = (&mut self.values[i * 4..i * 4 + 4]).try_into().unwrap();
+        // SAFETY: Vec4 is repr(C) and consists of exactly four f32 values, see the assertion above
+        unsafe { &mut *(values as *mut [f32; 4] as *mut Vec4) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal() {
+        let d = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let m = Mat4::from_diagonal(d);
+
+        assert_eq!(m.diagonal(), d);
+        assert_eq!(
+            m,
+            Mat4::scale(Vec3::new(1.0, 2.0, 3.0))
+                * Mat4::from_diagonal(Vec4::new(1.0, 1.0, 1.0, 4.0))
+        );
+        assert_eq!(m.get(1, 0), 0.0);
+        assert_eq!(Mat4::IDENTITY.diagonal(), Vec4::ONE);
+    }
+
+    #[test]
+    fn bytes() {
+        let m = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(Mat4::from_le_bytes(m.to_le_bytes()), m);
+        assert_eq!(Mat4::from_be_bytes(m.to_be_bytes()), m);
+        assert_eq!(m.to_le_bytes()[48..52], 1.0f32.to_le_bytes());
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Mat4::translate(Vec3::new(2.0, 0.0, 0.0));
+        let b = Mat4::translate(Vec3::new(4.0, 2.0, 0.0));
+
+        assert_eq!(a.lerp(b, 0.5), Mat4::translate(Vec3::new(3.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn homography() {
+        let src = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        let dst = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(3.0, 3.0),
+            Vec2::new(0.0, 2.0),
+        ];
+
+        let h = Mat4::homography(src, dst).unwrap();
+        for (s, d) in src.iter().zip(dst) {
+            let p = h * Vec4::new(s.x, s.y, 0.0, 1.0);
+            assert!((Vec2::new(p.x / p.w, p.y / p.w) - d).magnitude() < 1e-5);
+        }
+
+        // an affine mapping yields a matrix without perspective
+        let t = Mat4::homography(src, src.map(|p| p * 2.0 + Vec2::new(1.0, 0.0))).unwrap();
+        assert_eq!(
+            t,
+            Mat4::translate(Vec3::new(1.0, 0.0, 0.0)) * Mat4::scale(Vec3::new(2.0, 2.0, 1.0))
+        );
+
+        let collinear = [src[0], src[1], Vec2::new(2.0, 0.0), src[3]];
+        assert_eq!(Mat4::homography(collinear, dst), None);
+    }
+
+    #[test]
+    fn view_to_camera() {
+        let pos = Vec3::new(1.0, -2.0, 3.0);
+
+        for rot in [
+            Quaternion::IDENTITY,
+            Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 1.0),
+            Quaternion::axis_angle(Vec3::new(0.0, 1.0, 0.0), 180f32.to_radians()),
+            Quaternion::axis_angle(Vec3::new(1.0, 0.0, 0.0), 170f32.to_radians()),
+            Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 175f32.to_radians()),
+        ] {
+            let view = Mat4::world_to_local(pos, rot, Vec3::ONE);
+            let (p, r) = view.view_to_camera();
+
+            assert!((p - pos).magnitude() < 1e-5);
+            // q and -q are the same rotation
+            let d = r.x * rot.x + r.y * rot.y + r.z * rot.z + r.w * rot.w;
+            assert!((d.abs() - 1.0).abs() < 1e-5, "{r} != {rot}");
+        }
+    }
+
+    #[test]
+    fn perspective_physical() {
+        // a 24mm high sensor with a 12mm lens has a vertical fov of 90 degrees
+        let sensor = Vec2::new(36.0, 24.0);
+
+        assert_eq!(
+            Mat4::perspective_physical_vulkan(12.0, sensor, 0.1, 100.0),
+            Mat4::perspective_vulkan(90f32.to_radians(), 0.1, 100.0, 1.5)
+        );
+        assert_eq!(
+            Mat4::perspective_physical_opengl(12.0, sensor, 0.1, 100.0),
+            Mat4::perspective_opengl(90f32.to_radians(), 0.1, 100.0, 1.5)
+        );
+
+        let unshifted =
+            Mat4::perspective_physical_shifted_vulkan(12.0, sensor, Vec2::ZERO, 0.1, 100.0);
+        let expected = Mat4::perspective_physical_vulkan(12.0, sensor, 0.1, 100.0);
+        for (a, b) in unshifted.values.iter().zip(expected.values) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        // shifts are fractions of the larger sensor dimension on both axes, as in Blender,
+        // so a shift of 0.25 moves the view axis by 9mm, i.e. half the width or 3/4 of the height
+        let shifted = Mat4::perspective_physical_shifted_opengl(
+            12.0,
+            sensor,
+            Vec2::new(0.25, 0.25),
+            0.1,
+            100.0,
+        );
+        let p = shifted * Vec4::new(0.0, 0.0, 1.0, 1.0);
+        assert_near(p.x / p.w, -0.5);
+        assert_near(p.y / p.w, -0.75);
+
+        // the same holds for portrait sensors
+        let portrait = Mat4::perspective_physical_shifted_vulkan(
+            12.0,
+            Vec2::new(24.0, 36.0),
+            Vec2::new(1.0 / 3.0, 0.0),
+            0.1,
+            100.0,
+        );
+        let p = portrait * Vec4::new(0.0, 0.0, 1.0, 1.0);
+        assert_near(p.x / p.w, -1.0);
+        assert_near(p.y / p.w, 0.0);
+    }
+
+    fn assert_near(a: f32, b: f32) {
+        assert!((a - b).abs() <= 1e-4 * b.abs().max(1.0), "{a} != {b}");
+    }
+
+    #[test]
+    fn projection_introspection() {
+        let fov = 70f32.to_radians();
+
+        let p = Mat4::perspective_vulkan(fov, 0.1, 500.0, 16.0 / 9.0);
+        assert_near(p.perspective_fov(), fov);
+        assert_near(p.perspective_aspect(), 16.0 / 9.0);
+        let (n, f) = p.perspective_near_far_vulkan();
+        assert_near(n, 0.1);
+        assert_near(f, 500.0);
+
+        let p = Mat4::perspective_opengl(fov, 0.5, 20.0, 1.0);
+        let (n, f) = p.perspective_near_far_opengl();
+        assert_near(n, 0.5);
+        assert_near(f, 20.0);
+
+        let o = Mat4::orthographic_vulkan(-2.0, 4.0, -1.0, 3.0, 0.5, 10.0);
+        let (l, r) = o.orthographic_left_right();
+        let (b, t) = o.orthographic_bottom_top();
+        let (n, f) = o.orthographic_near_far_vulkan();
+        assert_near(l, -2.0);
+        assert_near(r, 4.0);
+        assert_near(b, -1.0);
+        assert_near(t, 3.0);
+        assert_near(n, 0.5);
+        assert_near(f, 10.0);
+
+        let o = Mat4::orthographic_opengl(-2.0, 4.0, -1.0, 3.0, 0.5, 10.0);
+        let (n, f) = o.orthographic_near_far_opengl();
+        assert_near(n, 0.5);
+        assert_near(f, 10.0);
+    }
+
+    #[test]
+    fn clip_space_conversion() {
+        let fov = 60f32.to_radians();
+        let gl = Mat4::perspective_opengl(fov, 0.1, 100.0, 1.5);
+        let vk = Mat4::perspective_vulkan(fov, 0.1, 100.0, 1.5);
+
+        for (a, b) in (Mat4::OPENGL_TO_WGPU * gl).values.iter().zip(vk.values) {
+            assert_near(*a, b);
+        }
+
+        let flipped = Mat4::OPENGL_TO_VULKAN * gl;
+        let p = flipped * Vec4::new(1.0, 1.0, 100.0, 1.0);
+        assert!(p.y < 0.0);
+        assert_near(p.z / p.w, 1.0);
+
+        assert_eq!(
+            Mat4::VULKAN_TO_OPENGL * Mat4::OPENGL_TO_VULKAN,
+            Mat4::IDENTITY
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let m = Mat4::local_to_world(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quaternion::axis_angle(Vec3::new(1.0, 1.0, 0.0), 1.0),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let p = Mat4::perspective_vulkan(1.0, 0.1, 100.0, 1.5);
+
+        for m in [m, p, p * m] {
+            let i = m.inverse();
+            for (a, b) in (m * i).values.iter().zip(Mat4::IDENTITY.values) {
+                assert!((a - b).abs() < 1e-4, "{m:?} * {i:?} is not the identity");
+            }
+            assert_eq!(m.try_inverse(1e-6), Some(i));
+        }
+
+        assert_eq!(Mat4::scale(Vec3::new(2.0, 4.0, 8.0)).determinant(), 64.0);
+        assert_eq!(
+            Mat4::scale(Vec3::new(2.0, 4.0, 8.0)).inverse(),
+            Mat4::scale(Vec3::new(0.5, 0.25, 0.125))
+        );
+        assert_eq!(Mat4::scale(Vec3::new(1.0, 0.0, 1.0)).try_inverse(0.0), None);
+
+        // nearly singular matrices
+        assert_eq!(
+            Mat4::scale(Vec3::new(1e-20, 1.0, 1.0)).try_inverse(1e-6),
+            None
+        );
+        let mut collinear = Mat4::IDENTITY;
+        collinear[(1, 0)] = 1.0;
+        collinear[(1, 1)] = 1e-7;
+        assert!(collinear.try_inverse(0.0).is_some());
+        assert_eq!(collinear.try_inverse(1e-6), None);
+    }
+
+    #[test]
+    fn look_at() {
+        let up = Vec3::new(0.0, 1.0, 0.0);
+
+        assert_eq!(
+            Mat4::look_at_lh(Vec3::new(0.0, 0.0, -5.0), Vec3::ZERO, up),
+            Mat4::translate(Vec3::new(0.0, 0.0, 5.0))
+        );
+        assert_eq!(
+            Mat4::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, up),
+            Mat4::translate(Vec3::new(0.0, 0.0, -5.0))
+        );
+
+        let eye = Vec3::new(3.0, 4.0, -2.0);
+        let target = Vec3::new(-1.0, 0.5, 6.0);
+        let dist = (target - eye).magnitude();
+
+        let lh = Mat4::look_at_lh(eye, target, up) * target.extend(1.0);
+        assert!((lh - Vec4::new(0.0, 0.0, dist, 1.0)).magnitude() < 1e-5);
+        let rh = Mat4::look_at_rh(eye, target, up) * target.extend(1.0);
+        assert!((rh - Vec4::new(0.0, 0.0, -dist, 1.0)).magnitude() < 1e-5);
+
+        // points above the camera stay above
+        let above = Mat4::look_at_lh(eye, target, up) * (eye + up).extend(1.0);
+        assert!(above.y > 0.0);
+        let above = Mat4::look_at_rh(eye, target, up) * (eye + up).extend(1.0);
+        assert!(above.y > 0.0);
+    }
+
+    #[test]
+    fn normal_matrix() {
+        let m = Mat4::local_to_world(
+            Vec3::new(5.0, 0.0, 0.0),
+            Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 0.5),
+            Vec3::new(4.0, 1.0, 1.0),
+        );
+
+        // the normal of the plane x = y stays perpendicular to the transformed plane
+        let tangent = m * Vec3::new(1.0, 1.0, 0.0);
+        let normal = m.normal_matrix() * Vec3::new(1.0, -1.0, 0.0);
+        assert!(tangent.dot(normal).abs() < 1e-5);
+
+        let r = Mat4::rotate(Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 1.0));
+        let n = r.normal_matrix() * Vec3::new(0.0, 1.0, 0.0);
+        assert!((n - r * Vec3::new(0.0, 1.0, 0.0)).magnitude() < 1e-5);
+    }
+
+    #[test]
+    fn format() {
+        let m = Mat4::translate(Vec3::new(1.0, 2.5, 3.0));
+
+        assert_eq!(
+            format!("{m}"),
+            "(1, 0, 0, 1)\n(0, 1, 0, 2.5)\n(0, 0, 1, 3)\n(0, 0, 0, 1)"
+        );
+        assert_eq!(
+            format!("{m:#?}"),
+            "Mat4 [\n    (1.0, 0.0, 0.0, 1.0),\n    (0.0, 1.0, 0.0, 2.5),\n    (0.0, 0.0, 1.0, 3.0),\n    (0.0, 0.0, 0.0, 1.0),\n]"
+        );
+        assert!(format!("{m:?}").starts_with("Mat4 { values: [1.0, "));
+    }
+
+    #[test]
+    fn rows_columns() {
+        let mut m = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(m.get_column(3), Vec4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(m.get_row(0), Vec4::new(1.0, 0.0, 0.0, 1.0));
+
+        m.set_column(0, Vec4::new(5.0, 6.0, 7.0, 8.0));
+        assert_eq!(m.get(0, 2), 7.0);
+        m.set_row(1, Vec4::new(-1.0, -2.0, -3.0, -4.0));
+        assert_eq!(m.get_column(0), Vec4::new(5.0, -1.0, 7.0, 8.0));
+        assert_eq!(m.get_row(1), Vec4::new(-1.0, -2.0, -3.0, -4.0));
+    }
+
+    #[test]
+    fn from_rows_cols() {
+        let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+        let c = Vec4::new(9.0, 10.0, 11.0, 12.0);
+        let d = Vec4::new(13.0, 14.0, 15.0, 16.0);
+
+        let m = Mat4::from_cols(a, b, c, d);
+        assert_eq!(m.get_column(2), c);
+        assert_eq!(m.get(1, 3), 8.0);
+        assert_eq!(Mat4::from_rows(a, b, c, d), m.transposed());
+
+        assert_eq!(
+            Mat4::from_cols(
+                Vec4::new(1.0, 0.0, 0.0, 0.0),
+                Vec4::new(0.0, 1.0, 0.0, 0.0),
+                Vec4::new(0.0, 0.0, 1.0, 0.0),
+                Vec4::new(1.0, 2.0, 3.0, 1.0)
+            ),
+            Mat4::translate(Vec3::new(1.0, 2.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn operators() {
+        let a = Mat4::from_diagonal(Vec4::new(1.0, 2.0, 3.0, 4.0));
+        let b = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+
+        let sum = a + b;
+        assert_eq!(sum.diagonal(), Vec4::new(2.0, 3.0, 4.0, 5.0));
+        assert_eq!(sum.get_column(3), Vec4::new(1.0, 2.0, 3.0, 5.0));
+        assert_eq!(sum - b, a);
+        assert_eq!(a * 2.0, a + a);
+        assert_eq!(2.0 * a, a + a);
+        assert_eq!((a * 2.0) / 2.0, a);
+        assert_eq!(a - a, Mat4::ZERO);
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+        c *= 3.0;
+        assert_eq!(c, a * 3.0);
+        c /= 3.0;
+        assert_eq!(c, a);
+        c *= b;
+        assert_eq!(c, a * b);
+    }
+
+    fn project_depth(m: Mat4, z: f32) -> f32 {
+        let p = m * Vec4::new(0.0, 0.0, z, 1.0);
+        p.z / p.w
+    }
+
+    fn assert_inverse(m: Mat4, inv: Mat4) {
+        for (a, b) in (m * inv).values.iter().zip(Mat4::IDENTITY.values) {
+            assert!(
+                (a - b).abs() < 1e-4,
+                "{m:#?} * {inv:#?} is not the identity"
+            );
+        }
+    }
+
+    #[test]
+    fn reversed_z() {
+        let fov = 60f32.to_radians();
+
+        let vk = Mat4::perspective_vulkan_reversed_z(fov, 0.1, 100.0, 1.5);
+        assert_near(project_depth(vk, 0.1), 1.0);
+        assert_near(project_depth(vk, 100.0), 0.0);
+        assert_inverse(
+            vk,
+            Mat4::inverse_perspective_vulkan_reversed_z(fov, 0.1, 100.0, 1.5),
+        );
+
+        let gl = Mat4::perspective_opengl_reversed_z(fov, 0.1, 100.0, 1.5);
+        assert_near(project_depth(gl, 0.1), 1.0);
+        assert_near(project_depth(gl, 100.0), -1.0);
+        assert_inverse(
+            gl,
+            Mat4::inverse_perspective_opengl_reversed_z(fov, 0.1, 100.0, 1.5),
+        );
+
+        assert_inverse(
+            Mat4::perspective_vulkan(fov, 0.1, 100.0, 1.5),
+            Mat4::inverse_perspective_vulkan(fov, 0.1, 100.0, 1.5),
+        );
+    }
+
+    #[test]
+    fn infinite_perspective() {
+        let fov = 60f32.to_radians();
+        let far = 1.0e7;
+
+        let vk = Mat4::perspective_infinite_vulkan(fov, 0.1, 1.5);
+        assert_near(project_depth(vk, 0.1), 0.0);
+        assert!((project_depth(vk, far) - 1.0).abs() < 1e-6);
+
+        let gl = Mat4::perspective_infinite_opengl(fov, 0.1, 1.5);
+        assert_near(project_depth(gl, 0.1), -1.0);
+        assert!((project_depth(gl, far) - 1.0).abs() < 1e-6);
+
+        let vk = Mat4::perspective_infinite_vulkan_reversed_z(fov, 0.1, 1.5);
+        assert_near(project_depth(vk, 0.1), 1.0);
+        assert!(project_depth(vk, far).abs() < 1e-6);
+
+        let gl = Mat4::perspective_infinite_opengl_reversed_z(fov, 0.1, 1.5);
+        assert_near(project_depth(gl, 0.1), 1.0);
+        assert!((project_depth(gl, far) + 1.0).abs() < 1e-6);
+
+        // the finite projection converges towards the infinite one
+        let finite = Mat4::perspective_vulkan(fov, 0.1, far, 1.5);
+        let infinite = Mat4::perspective_infinite_vulkan(fov, 0.1, 1.5);
+        for (a, b) in finite.values.iter().zip(infinite.values) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn frustum() {
+        let fov = 60f32.to_radians();
+        let top = 0.1 * (fov * 0.5).tan();
+        let right = top * 1.5;
+
+        let symmetric = Mat4::frustum_vulkan(-right, right, -top, top, 0.1, 100.0);
+        let perspective = Mat4::perspective_vulkan(fov, 0.1, 100.0, 1.5);
+        for (a, b) in symmetric.values.iter().zip(perspective.values) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        let symmetric = Mat4::frustum_opengl(-right, right, -top, top, 0.1, 100.0);
+        let perspective = Mat4::perspective_opengl(fov, 0.1, 100.0, 1.5);
+        for (a, b) in symmetric.values.iter().zip(perspective.values) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        // corners of the near plane map to the corners of clip space
+        let m = Mat4::frustum_vulkan(-1.0, 3.0, 0.5, 2.0, 1.0, 10.0);
+        let p = m * Vec4::new(-1.0, 0.5, 1.0, 1.0);
+        assert_near(p.x / p.w, -1.0);
+        assert_near(p.y / p.w, -1.0);
+        assert_near(p.z / p.w, 0.0);
+        let p = m * Vec4::new(30.0, 20.0, 10.0, 1.0);
+        assert_near(p.x / p.w, 1.0);
+        assert_near(p.y / p.w, 1.0);
+        assert_near(p.z / p.w, 1.0);
+
+        let m = Mat4::frustum_opengl(-1.0, 3.0, 0.5, 2.0, 1.0, 10.0);
+        let p = m * Vec4::new(3.0, 2.0, 1.0, 1.0);
+        assert_near(p.x / p.w, 1.0);
+        assert_near(p.y / p.w, 1.0);
+        assert_near(p.z / p.w, -1.0);
+    }
+
+    #[test]
+    fn reflect() {
+        let m = Mat4::reflect(Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.0, 3.0, 0.0));
+        let p = m * Vec4::new(1.0, 5.0, -1.0, 1.0);
+        assert_near(p.x, 1.0);
+        assert_near(p.y, -1.0);
+        assert_near(p.z, -1.0);
+
+        let n = Vec3::new(1.0, 1.0, 0.0);
+        let m = Mat4::reflect(Vec3::new(1.0, 0.0, 0.0), n);
+        let p = m * Vec4::new(0.0, 0.0, 4.0, 1.0);
+        assert_near(p.x, 1.0);
+        assert_near(p.y, 1.0);
+        assert_near(p.z, 4.0);
+
+        // reflecting twice is the identity
+        for (a, b) in (m * m).values.iter().zip(Mat4::IDENTITY.values) {
+            assert_near(*a, b);
+        }
+        assert_near(m.determinant(), -1.0);
+    }
+
+    #[test]
+    fn viewport() {
+        let vp = Rect::new(10.0, 20.0, 800.0, 600.0);
+
+        let m = Mat4::viewport_vulkan(vp, Vec2::new(0.0, 1.0));
+        let p = m * Vec4::new(-1.0, -1.0, 0.0, 1.0);
+        assert_eq!((p.x, p.y, p.z), (10.0, 20.0, 0.0));
+        let p = m * Vec4::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!((p.x, p.y, p.z), (810.0, 620.0, 1.0));
+
+        let m = Mat4::viewport_opengl(vp, Vec2::new(0.0, 1.0));
+        let p = m * Vec4::new(0.0, 0.0, -1.0, 1.0);
+        assert_eq!((p.x, p.y, p.z), (410.0, 320.0, 0.0));
+        let p = m * Vec4::new(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(p.z, 1.0);
+
+        // the picked region fills all of clip space
+        let pick = Mat4::pick_matrix(Vec2::new(110.0, 70.0), Vec2::new(20.0, 10.0), vp);
+        let m = Mat4::viewport_opengl(vp, Vec2::new(0.0, 1.0)).inverse();
+        let p = pick * m * Vec4::new(100.0, 65.0, 0.0, 1.0);
+        assert_near(p.x, -1.0);
+        assert_near(p.y, -1.0);
+        let p = pick * m * Vec4::new(120.0, 75.0, 0.0, 1.0);
+        assert_near(p.x, 1.0);
+        assert_near(p.y, 1.0);
+    }
+
+    #[test]
+    fn project() {
+        let vp = Rect::new(0.0, 0.0, 800.0, 600.0);
+        let view = Mat4::look_at_lh(
+            Vec3::new(1.0, 2.0, -5.0),
+            Vec3::ZERO,
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        let world = Vec3::new(0.5, -0.25, 1.0);
+
+        let m = Mat4::perspective_vulkan(60f32.to_radians(), 0.1, 100.0, 800.0 / 600.0) * view;
+        let screen = m.project_vulkan(Vec3::ZERO, vp);
+        assert_near(screen.x, 400.0);
+        assert_near(screen.y, 300.0);
+        assert!(screen.z > 0.0 && screen.z < 1.0);
+        let back = m.unproject_vulkan(m.project_vulkan(world, vp), vp);
+        assert!((back - world).magnitude() < 1e-3);
+
+        let m = Mat4::perspective_opengl(60f32.to_radians(), 0.1, 100.0, 800.0 / 600.0) * view;
+        let screen = m.project_opengl(Vec3::ZERO, vp);
+        assert_near(screen.x, 400.0);
+        assert_near(screen.y, 300.0);
+        assert!(screen.z > 0.0 && screen.z < 1.0);
+        let back = m.unproject_opengl(m.project_opengl(world, vp), vp);
+        assert!((back - world).magnitude() < 1e-3);
+    }
+
+    #[test]
+    fn orthonormalize() {
+        let r = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0).normalized(), 0.7);
+        let t = Vec3::new(1.0, 2.0, 3.0);
+        let expected = Mat4::local_to_world(t, r, Vec3::ONE);
+
+        let mut skew = Mat4::IDENTITY;
+        skew.set(1, 0, 0.3);
+        let drifted = Mat4::local_to_world(t, r, Vec3::new(2.0, 0.5, 3.0)) * skew;
+
+        let res = drifted.orthonormalized();
+        let z = res.get_column(2);
+        assert!(z.w == 0.0 && (Vec3::new(z.x, z.y, z.z).magnitude() - 1.0).abs() < 1e-5);
+        for (a, b) in res.values.iter().zip(expected.values) {
+            assert!((a - b).abs() < 1e-5, "{res:#?} != {expected:#?}");
+        }
+    }
+
+    #[test]
+    fn adjugate() {
+        let m = Mat4::from_rows(
+            Vec4::new(2.0, 0.0, 1.0, 0.0),
+            Vec4::new(1.0, 3.0, 0.0, 1.0),
+            Vec4::new(0.0, 1.0, 4.0, 0.0),
+            Vec4::new(1.0, 0.0, 0.0, 1.0),
+        );
+        assert_eq!(m.trace(), 10.0);
+
+        // expanding along any row or column yields the determinant
+        let det = m.determinant();
+        for i in 0..4 {
+            assert_near((0..4).map(|c| m[(c, i)] * m.cofactor(c, i)).sum(), det);
+            assert_near((0..4).map(|r| m[(i, r)] * m.cofactor(i, r)).sum(), det);
+        }
+
+        assert_eq!(m.adjugate(), m.cofactor_matrix().transposed());
+        for (a, b) in (m * m.adjugate()).values.iter().zip(Mat4::IDENTITY.values) {
+            assert_near(*a, b * det);
+        }
+    }
+
+    #[test]
+    fn ndc_convention() {
+        let fov = 60f32.to_radians();
+        let gl = Mat4::perspective_opengl(fov, 0.1, 100.0, 1.5);
+        let vk = Mat4::perspective_vulkan(fov, 0.1, 100.0, 1.5);
+
+        assert_eq!(
+            Mat4::perspective(fov, 0.1, 100.0, 1.5, NdcConvention::OpenGl),
+            gl
+        );
+        assert_eq!(
+            Mat4::perspective(fov, 0.1, 100.0, 1.5, NdcConvention::DirectX),
+            vk
+        );
+
+        let flipped = Mat4::OPENGL_TO_VULKAN * gl;
+        let res = Mat4::perspective(fov, 0.1, 100.0, 1.5, NdcConvention::Vulkan);
+        for (a, b) in res.values.iter().zip(flipped.values) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        let p = Mat4::orthographic(0.0, 4.0, 0.0, 2.0, 0.0, 10.0, NdcConvention::Vulkan)
+            * Vec4::new(0.0, 2.0, 10.0, 1.0);
+        assert_eq!((p.x, p.y, p.z), (-1.0, -1.0, 1.0));
+        let p = Mat4::orthographic(0.0, 4.0, 0.0, 2.0, 0.0, 10.0, NdcConvention::OpenGl)
+            * Vec4::new(0.0, 2.0, 10.0, 1.0);
+        assert_eq!((p.x, p.y, p.z), (-1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn classification() {
+        let r = Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 1.0);
+        let trs = Mat4::local_to_world(Vec3::new(1.0, 2.0, 3.0), r, Vec3::ONE);
+
+        assert!(Mat4::IDENTITY.is_identity(0.0));
+        assert!((trs * trs.inverse()).is_identity(1e-5));
+        assert!(!trs.is_identity(1e-5));
+
+        assert!(trs.is_affine(0.0));
+        assert!(trs.is_orthogonal(1e-5));
+        assert!(trs.is_invertible(1e-5));
+        assert!(Mat4::reflect(Vec3::ZERO, Vec3::new(1.0, 0.0, 0.0)).is_orthogonal(1e-5));
+
+        let scaled = trs * Mat4::scale(Vec3::new(1.0, 2.0, 1.0));
+        assert!(!scaled.is_orthogonal(1e-5));
+        assert!(scaled.is_affine(0.0));
+
+        let proj = Mat4::perspective_vulkan(1.0, 0.1, 100.0, 1.0);
+        assert!(!proj.is_affine(1e-5));
+        assert!(proj.is_invertible(1e-5));
+
+        assert!(!Mat4::scale(Vec3::new(1.0, 0.0, 1.0)).is_invertible(1e-5));
+    }
+
+    #[test]
+    fn inverse_affine() {
+        let r = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0).normalized(), 0.7);
+        let m = Mat4::local_to_world(Vec3::new(1.0, -2.0, 3.0), r, Vec3::new(2.0, 0.5, 3.0));
+
+        let expected = m.inverse();
+        let res = m.inverse_affine();
+        for (a, b) in res.values.iter().zip(expected.values) {
+            assert!((a - b).abs() < 1e-5, "{res:#?} != {expected:#?}");
+        }
+    }
+
+    #[test]
+    fn array_conversions() {
+        let m = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(m.to_cols_array_2d()[3], [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(m.to_rows_array_2d()[0], [1.0, 0.0, 0.0, 1.0]);
+
+        let flat: [f32; 16] = m.into();
+        assert_eq!(flat, m.as_slice());
+        let nested: [[f32; 4]; 4] = m.into();
+        assert_eq!(Mat4::from(nested), m);
+        assert_eq!(Mat4::from(flat), m);
+    }
+
+    #[test]
+    fn iter() {
+        let m = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+        assert!(m.iter().eq(m.as_slice().iter().copied()));
+        assert_eq!(m.into_iter().sum::<f32>(), 10.0);
+
+        let flat: Vec<f32> = (&m).into_iter().collect();
+        assert_eq!(flat, m.as_slice());
+    }
+
+    #[test]
+    fn axis_rotations() {
+        let check = |a: Mat4, b: Mat4| {
+            for (a, b) in a.values.iter().zip(b.values) {
+                assert!((a - b).abs() < 1e-5);
+            }
+        };
+        let rotate = |axis: Vec3, radians: f32| Mat4::rotate(Quaternion::axis_angle(axis, radians));
+
+        for radians in [0.5, -2.0, 3.0] {
+            check(
+                Mat4::rotate_x(radians),
+                rotate(Vec3::new(1.0, 0.0, 0.0), radians),
+            );
+            check(
+                Mat4::rotate_y(radians),
+                rotate(Vec3::new(0.0, 1.0, 0.0), radians),
+            );
+            check(
+                Mat4::rotate_z(radians),
+                rotate(Vec3::new(0.0, 0.0, 1.0), radians),
+            );
+
+            let axis = Vec3::new(1.0, -2.0, 3.0);
+            check(
+                Mat4::rotate_axis_angle(axis, radians),
+                rotate(axis, radians),
+            );
+        }
+    }
+
+    #[test]
+    fn get_trs() {
+        let t = Vec3::new(1.0, -2.0, 3.0);
+        let r = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+
+        for s in [Vec3::new(2.0, 0.5, 3.0), Vec3::new(-2.0, 0.5, 3.0)] {
+            let m = Mat4::local_to_world(t, r, s);
+            assert!((m.get_translation() - t).magnitude() < 1e-5);
+            assert!((m.get_scale() - s).magnitude() < 1e-5);
+
+            let q = m.get_rotation();
+            assert!((q.forward() - r.forward()).magnitude() < 1e-5);
+            assert!((q.up() - r.up()).magnitude() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn column_access() {
+        let mut m = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(m.get_column(3), Vec4::new(1.0, 2.0, 3.0, 1.0));
+
+        m.set_column(2, Vec4::new(1.0, 1.0, 1.0, 1.0));
+        assert_eq!(m.get_row(2), Vec4::new(0.0, 0.0, 1.0, 3.0));
+        assert_eq!(m.get_row(0), Vec4::new(1.0, 0.0, 1.0, 1.0));
+
+        // indexing follows the storage order
+        if cfg!(feature = "mat-row-major") {
+            assert_eq!(m[3], m.get_row(3));
+        } else {
+            assert_eq!(m[3], m.get_column(3));
+        }
+        m[1].y = 5.0;
+        assert_eq!(m[(1, 1)], 5.0);
+        m[0] = Vec4::ONE;
+        assert_eq!(m.as_slice()[..4], [1.0; 4]);
+    }
+
+    #[test]
+    fn interpolate_trs() {
+        let axis = Vec3::new(0.0, 1.0, 0.0);
+        let a = Mat4::local_to_world(Vec3::ZERO, Quaternion::IDENTITY, Vec3::ONE);
+        let b = Mat4::local_to_world(
+            Vec3::new(2.0, 0.0, 0.0),
+            Quaternion::axis_angle(axis, 170f32.to_radians()),
+            Vec3::new(3.0, 3.0, 3.0),
+        );
+
+        let res = a.interpolate_trs(b, 0.5);
+        let expected = Mat4::local_to_world(
+            Vec3::new(1.0, 0.0, 0.0),
+            Quaternion::axis_angle(axis, 85f32.to_radians()),
+            Vec3::new(2.0, 2.0, 2.0),
+        );
+        for (a, b) in res.values.iter().zip(expected.values) {
+            assert!((a - b).abs() < 1e-4, "{res:#?} != {expected:#?}");
+        }
+        assert_eq!(a.interpolate_trs(b, 2.0), a.interpolate_trs(b, 1.0));
+    }
+
+    #[test]
+    fn storage_order_conversions() {
+        let m = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+
+        let cols = m.to_col_major_array();
+        assert_eq!(cols[12..15], [1.0, 2.0, 3.0]);
+        let rows = m.to_row_major_array();
+        assert_eq!([rows[3], rows[7], rows[11]], [1.0, 2.0, 3.0]);
+
+        assert_eq!(Mat4::from_col_major(cols), m);
+        assert_eq!(Mat4::from_row_major(rows), m);
+        assert_eq!(Mat4::from_row_major(cols), m.transposed());
+    }
+
+    #[test]
+    fn const_construction() {
+        // rotates y up to z up
+        const Y_UP_TO_Z_UP: Mat4 =
+            Mat4::rotate_x_sin_cos(1.0, 0.0).mul_mat4(&Mat4::scale(Vec3::new(2.0, 2.0, 2.0)));
+        const TRANSPOSED: Mat4 = Y_UP_TO_Z_UP.transposed();
+
+        let expected = Mat4::rotate_x(90f32.to_radians()) * Mat4::scale(Vec3::new(2.0, 2.0, 2.0));
+        for (a, b) in Y_UP_TO_Z_UP.values.iter().zip(expected.values) {
+            assert!((a - b).abs() < 1e-5);
+        }
+        assert_eq!(TRANSPOSED, Y_UP_TO_Z_UP.transposed());
+        let rotated = Mat4::rotate_z_sin_cos(0.6, 0.8);
+        for (a, b) in rotated
+            .values
+            .iter()
+            .zip(Mat4::rotate_z(0.6f32.asin()).values)
+        {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn mat3_embedding() {
+        let r = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+        let view = Mat4::look_at_lh(
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::ZERO,
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+
+        let m = Mat4::rotate(r);
+        assert_eq!(Mat4::from_mat3(m.to_mat3()), m);
+
+        let sky = view.rotation_scale_part();
+        assert_eq!(sky.get_translation(), Vec3::ZERO);
+        assert_eq!(sky.to_mat3(), view.to_mat3());
+        assert_eq!(sky.get_row(3), Vec4::new(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn horizontal_fov() {
+        // a square viewport has the same fov in both directions
+        assert_near(fovx_to_fovy(1.2, 1.0), 1.2);
+        // 90 degrees horizontally at 2:1 is 2 * atan(0.5) vertically
+        assert_near(fovx_to_fovy(90f32.to_radians(), 2.0), 2.0 * 0.5f32.atan());
+        assert_near(fovy_to_fovx(fovx_to_fovy(1.2, 16.0 / 9.0), 16.0 / 9.0), 1.2);
+
+        let p = Mat4::perspective_horizontal_vulkan(90f32.to_radians(), 0.1, 100.0, 2.0);
+        let v = p * Vec4::new(1.0, 0.5, 1.0, 1.0);
+        assert_near(v.x / v.w, 1.0);
+        assert_near(v.y / v.w, 1.0);
+        let p = Mat4::perspective_horizontal_opengl(90f32.to_radians(), 0.1, 100.0, 2.0);
+        assert_near(p.perspective_aspect(), 2.0);
+        assert_near(p.perspective_fov(), 2.0 * 0.5f32.atan());
+    }
+}
@@ -0,0 +1,86 @@
+use num_traits::{One, Zero};
+
+use crate::{Mat4, Quaternion, Vec2, Vec3, Vec4};
+
+macro_rules! impl_zero_one {
+    ($t:ident) => {
+        impl Zero for $t {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == Self::ZERO
+            }
+        }
+
+        /// The multiplicative identity for componentwise multiplication
+        impl One for $t {
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            fn is_one(&self) -> bool {
+                *self == Self::ONE
+            }
+        }
+    };
+}
+
+impl_zero_one!(Vec2);
+impl_zero_one!(Vec3);
+impl_zero_one!(Vec4);
+
+impl One for Quaternion {
+    fn one() -> Self {
+        Self::IDENTITY
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+}
+
+impl Zero for Mat4 {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl One for Mat4 {
+    fn one() -> Self {
+        Self::IDENTITY
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product<T: One + Copy>(values: &[T]) -> T {
+        values.iter().fold(T::one(), |acc, v| acc * *v)
+    }
+
+    #[test]
+    fn zero_one() {
+        assert!(Vec3::zero().is_zero());
+        assert!(Vec2::one().is_one());
+        assert_eq!(
+            product(&[Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::ONE * 2.0]),
+            Vec4::new(2.0, 4.0, 6.0, 8.0)
+        );
+
+        let t = Mat4::translate(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(product(&[t, Mat4::one()]), t);
+        assert!(Quaternion::one().is_one());
+        assert!((t - t).is_zero());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{centroid, Vec2};
+
+/// The minimum translation separating two overlapping shapes
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Penetration {
+    /// The separating axis, normalized and pointing from the first shape towards the second one
+    pub axis: Vec2,
+    /// The distance the second shape has to be moved along `axis` to resolve the overlap
+    pub depth: f32,
+}
+
+/// The winding order of a polygon's vertices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Returns the signed area of a simple polygon using the shoelace formula.
+///
+/// The area is positive for counter clock wise and negative for clock wise winding.
+pub fn polygon_signed_area(poly: &[Vec2]) -> f32 {
+    let mut sum = 0.0;
+    for (i, p) in poly.iter().enumerate() {
+        let q = poly[(i + 1) % poly.len()];
+        sum += p.x * q.y - q.x * p.y;
+    }
+
+    sum * 0.5
+}
+
+/// Returns the winding order of a simple polygon,
+/// or `None` if its area is zero
+pub fn polygon_winding(poly: &[Vec2]) -> Option<Winding> {
+    let area = polygon_signed_area(poly);
+    if area > 0.0 {
+        Some(Winding::CounterClockwise)
+    } else if area < 0.0 {
+        Some(Winding::Clockwise)
+    } else {
+        None
+    }
+}
+
+/// Reverses the vertex order of `poly` if necessary, so that it has the given `winding`.
+///
+/// Polygons with zero area are left untouched.
+pub fn set_polygon_winding(poly: &mut [Vec2], winding: Winding) {
+    if polygon_winding(poly).is_some_and(|w| w != winding) {
+        poly.reverse();
+    }
+}
+
+/// Returns the centroid (center of mass) of a simple polygon.
+///
+/// Falls back to the average of the vertices if the polygon has zero area.
+pub fn polygon_centroid(poly: &[Vec2]) -> Vec2 {
+    let mut c = Vec2::ZERO;
+    let mut area = 0.0;
+    for (i, p) in poly.iter().enumerate() {
+        let q = poly[(i + 1) % poly.len()];
+        let cross = p.x * q.y - q.x * p.y;
+        c += (*p + q) * cross;
+        area += cross;
+    }
+
+    if area == 0.0 {
+        return centroid(poly);
+    }
+
+    // area is twice the polygon's area here
+    c / (3.0 * area)
+}
+
+/// Returns the corners of a rectangle centered at `center` with `half_extents`,
+/// rotated counter clock wise by `radians`
+pub fn rotated_rect(center: Vec2, half_extents: Vec2, radians: f32) -> [Vec2; 4] {
+    let (sin, cos) = radians.sin_cos();
+    let x = Vec2::new(cos, sin) * half_extents.x;
+    let y = Vec2::new(-sin, cos) * half_extents.y;
+
+    [
+        center - x - y,
+        center + x - y,
+        center + x + y,
+        center - x + y,
+    ]
+}
+
+fn project(poly: &[Vec2], axis: Vec2) -> (f32, f32) {
+    poly.iter()
+        .map(|p| p.dot(axis))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
+
+/// Tests two convex polygons for overlap using the separating axis theorem.
+///
+/// The vertices of both polygons may be in either winding order.
+/// Returns `None` if the polygons do not overlap (touching does not count as overlapping).
+pub fn sat_overlap(a: &[Vec2], b: &[Vec2]) -> Option<Penetration> {
+    let mut best: Option<Penetration> = None;
+
+    for poly in [a, b] {
+        for (i, p) in poly.iter().enumerate() {
+            let edge = poly[(i + 1) % poly.len()] - *p;
+            if edge.sqr_magnitude() == 0.0 {
+                continue;
+            }
+            let axis = Vec2::new(-edge.y, edge.x).normalized();
+
+            let (min_a, max_a) = project(a, axis);
+            let (min_b, max_b) = project(b, axis);
+            let depth = max_a.min(max_b) - min_a.max(min_b);
+            if depth <= 0.0 {
+                return None;
+            }
+
+            if best.is_none_or(|b| depth < b.depth) {
+                best = Some(Penetration { axis, depth });
+            }
+        }
+    }
+
+    best.map(|mut p| {
+        if (centroid(b) - centroid(a)).dot(p.axis) < 0.0 {
+            p.axis = -p.axis;
+        }
+        p
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sat() {
+        let a = rotated_rect(Vec2::ZERO, Vec2::ONE, 0.0);
+        let b = rotated_rect(Vec2::new(1.5, 0.0), Vec2::ONE, 0.0);
+        let c = rotated_rect(Vec2::new(3.0, 0.0), Vec2::ONE, 0.0);
+
+        let p = sat_overlap(&a, &b).unwrap();
+        assert_eq!(p.axis, Vec2::new(1.0, 0.0));
+        assert_eq!(p.depth, 0.5);
+
+        let p = sat_overlap(&b, &a).unwrap();
+        assert_eq!(p.axis, Vec2::new(-1.0, 0.0));
+
+        assert_eq!(sat_overlap(&a, &c), None);
+
+        // a diamond between the two boxes only overlaps with a when rotated
+        let d = rotated_rect(Vec2::new(2.3, 0.0), Vec2::ONE, 45f32.to_radians());
+        assert!(sat_overlap(&a, &d).is_some());
+        assert_eq!(
+            sat_overlap(&a, &rotated_rect(Vec2::new(2.3, 2.3), Vec2::ONE, 0.0)),
+            None
+        );
+
+        let tri = [
+            Vec2::new(0.0, 2.0),
+            Vec2::new(-0.5, 0.9),
+            Vec2::new(0.5, 0.9),
+        ];
+        let p = sat_overlap(&a, &tri).unwrap();
+        assert!((p.depth - 0.1).abs() < 1e-5);
+        assert_eq!(p.axis, Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn area_centroid_winding() {
+        let mut square = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(3.0, 1.0),
+            Vec2::new(3.0, 3.0),
+            Vec2::new(1.0, 3.0),
+        ];
+
+        assert_eq!(polygon_signed_area(&square), 4.0);
+        assert_eq!(polygon_winding(&square), Some(Winding::CounterClockwise));
+        assert_eq!(polygon_centroid(&square), Vec2::new(2.0, 2.0));
+
+        set_polygon_winding(&mut square, Winding::Clockwise);
+        assert_eq!(polygon_signed_area(&square), -4.0);
+        assert_eq!(polygon_winding(&square), Some(Winding::Clockwise));
+        assert_eq!(polygon_centroid(&square), Vec2::new(2.0, 2.0));
+
+        // L-shape, centroid is not the vertex average
+        let l = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_eq!(polygon_signed_area(&l), 3.0);
+        assert_eq!(polygon_centroid(&l), Vec2::new(5.0 / 6.0, 5.0 / 6.0));
+
+        let line = [Vec2::ZERO, Vec2::ONE];
+        assert_eq!(polygon_winding(&line), None);
+        assert_eq!(polygon_centroid(&line), Vec2::new(0.5, 0.5));
+    }
+}
@@ -1,8 +1,8 @@
 use std::fmt::Display;
 
-use crate::Vec3;
+use crate::{Lerp, Mat4, Vec3};
 
-use auto_ops::impl_op_ex;
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -36,6 +36,36 @@ impl Quaternion {
         Self { x, y, z, w }
     }
 
+    /// Returns the components of `self` as an array, in the order x, y, z, w
+    pub const fn to_array(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Returns `true` if all components of `self` are neither infinite nor NaN
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Returns whether the magnitude of `self` is within `epsilon` of 1,
+    /// i.e. whether it represents a valid rotation
+    pub fn is_normalized(&self, epsilon: f32) -> bool {
+        (self.dot(*self).sqrt() - 1.0).abs() <= epsilon
+    }
+
+    /// Returns a normalized copy of `self`, or the identity if `self` can not be normalized
+    /// because it is (nearly) zero or contains non-finite values.
+    ///
+    /// Use this to sanitize quaternions from untrusted sources, e.g. deserialized user data.
+    #[must_use]
+    pub fn normalize_or_identity(&self) -> Quaternion {
+        let m = self.dot(*self).sqrt();
+        if !m.is_finite() || m < 1e-6 {
+            return Self::IDENTITY;
+        }
+
+        Self::new(self.x / m, self.y / m, self.z / m, self.w / m)
+    }
+
     /// Creates a rotation of `radians` radians around `axis`.
     ///
     /// The rotation will be counter clock wise when looking along the direction of `axis`.
@@ -51,6 +81,269 @@ impl Quaternion {
         }
     }
 
+    /// Creates a rotation of `radians` radians around the x axis
+    pub fn from_rotation_x(radians: f32) -> Self {
+        let (sin, cos) = (radians * 0.5).sin_cos();
+        Self::new(sin, 0.0, 0.0, cos)
+    }
+
+    /// Creates a rotation of `radians` radians around the y axis
+    pub fn from_rotation_y(radians: f32) -> Self {
+        let (sin, cos) = (radians * 0.5).sin_cos();
+        Self::new(0.0, sin, 0.0, cos)
+    }
+
+    /// Creates a rotation of `radians` radians around the z axis
+    pub fn from_rotation_z(radians: f32) -> Self {
+        let (sin, cos) = (radians * 0.5).sin_cos();
+        Self::new(0.0, 0.0, sin, cos)
+    }
+
+    /// Returns the normalized rotation axis and the angle in radians of `self`,
+    /// i.e. the inverse of [`axis_angle()`](Self::axis_angle()).
+    ///
+    /// The angle is in \[0; pi\]. For rotations that are (nearly) the identity,
+    /// the axis is arbitrary and (1, 0, 0) is returned.
+    pub fn to_axis_angle(&self) -> (Vec3, f32) {
+        // q and -q represent the same rotation, pick the one with the smaller angle
+        let sign = if self.w < 0.0 { -1.0 } else { 1.0 };
+        let v = Vec3::new(self.x, self.y, self.z) * sign;
+        let sin = v.magnitude();
+
+        if sin < 1e-7 {
+            return (Vec3::new(1.0, 0.0, 0.0), 0.0);
+        }
+
+        let radians = 2.0 * sin.atan2(self.w * sign);
+        (v / sin, radians)
+    }
+
+    /// Creates a rotation from a rotation vector (exponential map),
+    /// i.e. a rotation around `v` by `v.magnitude()` radians
+    pub fn from_scaled_axis(v: Vec3) -> Self {
+        let radians = v.magnitude();
+        if radians < 1e-7 {
+            // first order approximation, avoids dividing by zero
+            return Self::new(v.x * 0.5, v.y * 0.5, v.z * 0.5, 1.0);
+        }
+
+        Self::axis_angle(v, radians)
+    }
+
+    /// Returns the rotation vector (logarithmic map) of `self`, i.e. the rotation axis scaled by the angle in radians.
+    ///
+    /// This is the inverse of [`from_scaled_axis()`](Self::from_scaled_axis()).
+    pub fn to_scaled_axis(&self) -> Vec3 {
+        let (axis, radians) = self.to_axis_angle();
+        axis * radians
+    }
+
+    /// Integrates the world space `angular_velocity` (axis scaled by radians per second)
+    /// over `dt` seconds and applies it to `self`.
+    ///
+    /// Uses the exponential map, which is exact for a constant angular velocity,
+    /// and renormalizes the result so rounding errors do not accumulate over many steps.
+    #[must_use]
+    pub fn integrate(&self, angular_velocity: Vec3, dt: f32) -> Quaternion {
+        (Self::from_scaled_axis(angular_velocity * dt) * self).normalize_or_identity()
+    }
+
+    /// Creates the shortest rotation that rotates the direction `from` onto the direction `to`.
+    ///
+    /// The directions do not need to be normalized.
+    /// If they point in opposite directions, the rotation is 180 degrees around an arbitrary perpendicular axis.
+    pub fn from_to_rotation(from: Vec3, to: Vec3) -> Self {
+        let from = from.normalized();
+        let to = to.normalized();
+        let dot = from.dot(to);
+
+        if dot < -0.999999 {
+            // any axis perpendicular to `from` works, pick the one least parallel to it
+            let helper = if from.x.abs() < 0.9 {
+                Vec3::new(1.0, 0.0, 0.0)
+            } else {
+                Vec3::new(0.0, 1.0, 0.0)
+            };
+            return Self::axis_angle(from.cross(helper), std::f32::consts::PI);
+        }
+
+        // the half-way quaternion: (from x to, 1 + from . to) has twice the half angle
+        let axis = from.cross(to);
+        let w = 1.0 + dot;
+        let m = (axis.sqr_magnitude() + w * w).sqrt();
+        Self::new(axis.x / m, axis.y / m, axis.z / m, w / m)
+    }
+
+    /// Creates the rotation that maps the x, y and z axes onto `right`, `up` and `forward`.
+    ///
+    /// The axes have to form an orthonormal basis.
+    pub(crate) fn from_axes(right: Vec3, up: Vec3, forward: Vec3) -> Self {
+        // Shepperd's method: divide by the largest of the four possible denominators
+        let trace = right.x + up.y + forward.z;
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self {
+                x: (up.z - forward.y) / s,
+                y: (forward.x - right.z) / s,
+                z: (right.y - up.x) / s,
+                w: 0.25 * s,
+            }
+        } else if right.x > up.y && right.x > forward.z {
+            let s = (1.0 + right.x - up.y - forward.z).sqrt() * 2.0;
+            Self {
+                x: 0.25 * s,
+                y: (up.x + right.y) / s,
+                z: (forward.x + right.z) / s,
+                w: (up.z - forward.y) / s,
+            }
+        } else if up.y > forward.z {
+            let s = (1.0 + up.y - right.x - forward.z).sqrt() * 2.0;
+            Self {
+                x: (up.x + right.y) / s,
+                y: 0.25 * s,
+                z: (forward.y + up.z) / s,
+                w: (forward.x - right.z) / s,
+            }
+        } else {
+            let s = (1.0 + forward.z - right.x - up.y).sqrt() * 2.0;
+            Self {
+                x: (forward.x + right.z) / s,
+                y: (forward.y + up.z) / s,
+                z: 0.25 * s,
+                w: (right.y - up.x) / s,
+            }
+        }
+    }
+
+    /// Extracts the rotation from the upper left 3x3 block of `m`.
+    ///
+    /// Scale is removed by normalizing the basis vectors first,
+    /// skewed or mirrored matrices do not have a meaningful rotation.
+    pub fn from_mat4(m: &Mat4) -> Self {
+        let axis = |c: usize| {
+            let col = m.get_column(c);
+            Vec3::new(col.x, col.y, col.z).normalized()
+        };
+
+        Self::from_axes(axis(0), axis(1), axis(2))
+    }
+
+    /// Returns the 4D dot product of `self` and `b`
+    fn dot(&self, b: Quaternion) -> f32 {
+        self.x * b.x + self.y * b.y + self.z * b.z + self.w * b.w
+    }
+
+    /// Returns the rotation that rotates `self` onto `other`, i.e. `r` with `r * self == other`.
+    ///
+    /// This is the same as `other / self`.
+    pub fn delta_to(&self, other: Quaternion) -> Quaternion {
+        other / self
+    }
+
+    /// Rotates `self` towards `target`, but by at most `max_radians` radians, taking the shortest path.
+    ///
+    /// `target` is returned if it is closer than `max_radians`, so this never overshoots.
+    /// Calling this every frame with `max_radians = speed * delta_time` turns at a constant angular speed.
+    pub fn rotate_towards(&self, target: Quaternion, max_radians: f32) -> Quaternion {
+        let radians = 2.0 * self.dot(target).abs().min(1.0).acos();
+        if radians <= max_radians {
+            return target;
+        }
+
+        Self::slerp(*self, target, max_radians / radians)
+    }
+
+    /// Linearly interpolates between `a` and `b` and normalizes the result, taking the shortest path.
+    ///
+    /// This is much cheaper than [`slerp()`](Self::slerp()), but does not rotate with constant angular velocity.
+    /// The difference is negligible for rotations that are close to each other.
+    /// `t` is not clamped.
+    pub fn nlerp(a: Quaternion, b: Quaternion, t: f32) -> Quaternion {
+        let dot = a.dot(b);
+        let tb = if dot < 0.0 { -t } else { t };
+        let ta = 1.0 - t;
+
+        let x = a.x * ta + b.x * tb;
+        let y = a.y * ta + b.y * tb;
+        let z = a.z * ta + b.z * tb;
+        let w = a.w * ta + b.w * tb;
+        let m = (x * x + y * y + z * z + w * w).sqrt();
+
+        Quaternion::new(x / m, y / m, z / m, w / m)
+    }
+
+    /// Spherically interpolates between `a` and `b`, taking the shortest path.
+    ///
+    /// The rotation happens with constant angular velocity.
+    /// `t` is not clamped, values outside of \[0; 1\] extrapolate.
+    pub fn slerp(a: Quaternion, b: Quaternion, t: f32) -> Quaternion {
+        let dot = a.dot(b);
+        let cos = dot.abs();
+
+        // sin(theta) approaches zero for nearly identical rotations,
+        // nlerp is indistinguishable from slerp there
+        if cos > 0.9995 {
+            return Self::nlerp(a, b, t);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let ta = ((1.0 - t) * theta).sin() / sin;
+        let tb = (t * theta).sin() / sin * dot.signum();
+
+        Quaternion {
+            x: a.x * ta + b.x * tb,
+            y: a.y * ta + b.y * tb,
+            z: a.z * ta + b.z * tb,
+            w: a.w * ta + b.w * tb,
+        }
+    }
+
+    /// Returns the conjugate of `self`, i.e. `self` with its vector part negated.
+    ///
+    /// For unit quaternions, this is the inverse rotation. This is also what unary `-` returns.
+    pub const fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the multiplicative inverse of `self`, which does not need to be normalized.
+    ///
+    /// For unit quaternions, [`conjugate()`](Self::conjugate()) is cheaper and yields the same result.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let sqr_magnitude = self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w;
+        let c = self.conjugate();
+        let res = Self::new(
+            c.x / sqr_magnitude,
+            c.y / sqr_magnitude,
+            c.z / sqr_magnitude,
+            c.w / sqr_magnitude,
+        );
+
+        debug_validate!("Quaternion::inverse", res, self);
+        res
+    }
+
+    /// Splits `self` into a rotation around `axis` (the twist)
+    /// and the remaining rotation of `axis` itself (the swing).
+    ///
+    /// Returns `(swing, twist)`, so that `swing * twist == self`.
+    /// `self` has to be normalized, `axis` does not need to be.
+    pub fn swing_twist(&self, axis: Vec3) -> (Quaternion, Quaternion) {
+        let axis = axis.normalized();
+        let p = axis * axis.dot(Vec3::new(self.x, self.y, self.z));
+        let m = (p.sqr_magnitude() + self.w * self.w).sqrt();
+
+        // a rotation of 180 degrees perpendicular to the axis has no twist at all
+        let twist = if m < 1e-7 {
+            Self::IDENTITY
+        } else {
+            Self::new(p.x / m, p.y / m, p.z / m, self.w / m)
+        };
+
+        (self * twist.conjugate(), twist)
+    }
+
     /// Returns the vector (1, 0, 0) rotated by `self`
     pub fn right(&self) -> Vec3 {
         Vec3 {
@@ -135,13 +428,53 @@ impl Quaternion {
     }
 }
 
+byte_conversions!(Quaternion, x, y, z, w);
+
+/// Interpolates using normalized linear interpolation (nlerp).
+///
+/// Use [`Slerp`] to interpolate with constant angular velocity instead.
+impl Lerp for Quaternion {
+    fn lerp_unclamped(&self, b: Self, t: f32) -> Self {
+        Quaternion::nlerp(*self, b, t)
+    }
+}
+
+/// A [`Quaternion`] whose [`Lerp`] implementation uses spherical linear interpolation (slerp)
+/// instead of nlerp.
+///
+/// Slerp rotates with constant angular velocity, but is more expensive to compute.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Slerp(pub Quaternion);
+
+impl Lerp for Slerp {
+    fn lerp_unclamped(&self, b: Self, t: f32) -> Self {
+        Slerp(Quaternion::slerp(self.0, b.0, t))
+    }
+}
+
+impl From<Quaternion> for Slerp {
+    fn from(q: Quaternion) -> Self {
+        Slerp(q)
+    }
+}
+
+impl From<Slerp> for Quaternion {
+    fn from(s: Slerp) -> Self {
+        s.0
+    }
+}
+
 impl_op_ex!(*|a: &Quaternion, b: &Quaternion| -> Quaternion {
-    Quaternion {
+    let res = Quaternion {
         x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
         y: a.w * b.y + a.y * b.w + a.z * b.x - a.x * b.z,
         z: a.w * b.z + a.z * b.w + a.x * b.y - a.y * b.x,
         w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
-    }
+    };
+    debug_validate!("Quaternion * Quaternion", res, a, b);
+    res
 });
 
 impl_op_ex!(*|a: &Quaternion, b: &Vec3| -> Vec3 {
@@ -154,22 +487,38 @@ impl_op_ex!(*|a: &Quaternion, b: &Vec3| -> Vec3 {
     let yy = a.y * b.y;
     let zz = a.z * b.z;
 
-    Vec3 {
+    let res = Vec3 {
         x: b.x * (x2 - y2 - z2 + w2)
             + 2.0 * (a.x * yy + a.x * zz + a.w * a.y * b.z - a.w * a.z * b.y),
         y: b.y * (-x2 + y2 - z2 + w2)
             + 2.0 * (a.y * xx + a.y * zz + a.w * a.z * b.x - a.w * a.x * b.z),
         z: b.z * (-x2 - y2 + z2 + w2)
             + 2.0 * (a.z * xx + a.z * yy + a.w * a.x * b.y - a.w * a.y * b.x),
-    }
+    };
+    debug_validate!("Quaternion * Vec3", res, a, b);
+    res
 });
 
-impl_op_ex!(-|a: &Quaternion| -> Quaternion {
+impl_op_ex!(-|a: &Quaternion| -> Quaternion { a.conjugate() });
+
+// `a / b` is `a * b.inverse()`, i.e. the rotation that is left after undoing `b` from `a`
+impl_op_ex!(/ |a: &Quaternion, b: &Quaternion| -> Quaternion {
+    let res = a * b.inverse();
+    debug_validate!("Quaternion / Quaternion", res, a, b);
+    res
+});
+
+// componentwise arithmetic, e.g. for weighted blending of several rotations followed by a normalization
+impl_op_ex!(+= |a: &mut Quaternion, b: &Quaternion| { a.x += b.x; a.y += b.y; a.z += b.z; a.w += b.w; });
+impl_op_ex!(*= |a: &mut Quaternion, b: &f32| { a.x *= b; a.y *= b; a.z *= b; a.w *= b; });
+
+impl_op_ex!(+ |a: &Quaternion, b: &Quaternion| -> Quaternion { Quaternion{x: a.x + b.x, y: a.y + b.y, z: a.z + b.z, w: a.w + b.w } });
+impl_op_ex_commutative!(*|a: &Quaternion, b: &f32| -> Quaternion {
     Quaternion {
-        x: -a.x,
-        y: -a.y,
-        z: -a.z,
-        w: a.w,
+        x: a.x * b,
+        y: a.y * b,
+        z: a.z * b,
+        w: a.w * b,
     }
 });
 
@@ -183,3 +532,314 @@ impl From<[f32; 4]> for Quaternion {
         }
     }
 }
+
+impl From<Quaternion> for [f32; 4] {
+    fn from(v: Quaternion) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Quaternion {
+    fn from((x, y, z, w): (f32, f32, f32, f32)) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Quaternion> for (f32, f32, f32, f32) {
+    fn from(v: Quaternion) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_quat_eq(a: Quaternion, b: Quaternion) {
+        let d = Vec3::new(a.x - b.x, a.y - b.y, a.z - b.z).magnitude() + (a.w - b.w).abs();
+        assert!(d < 1e-5, "{a} != {b}");
+    }
+
+    /// Asserts that `a` and `b` are equal or negated, i.e. represent the same rotation
+    fn assert_same_rotation(a: Quaternion, b: Quaternion) {
+        if a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w < 0.0 {
+            assert_quat_eq(a, Quaternion::new(-b.x, -b.y, -b.z, -b.w));
+        } else {
+            assert_quat_eq(a, b);
+        }
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Quaternion::IDENTITY;
+        let b = Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 90f32.to_radians());
+        let half = Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 45f32.to_radians());
+
+        assert_quat_eq(a.lerp(b, 0.5), half);
+        assert_quat_eq(Slerp(a).lerp(Slerp(b), 0.5).0, half);
+        assert_quat_eq(
+            Slerp(a).lerp(Slerp(b), 1.0 / 3.0).0,
+            Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 30f32.to_radians()),
+        );
+
+        // -b represents the same rotation as b, interpolation has to take the shortest path
+        let neg_b = Quaternion::new(-b.x, -b.y, -b.z, -b.w);
+        assert_quat_eq(a.lerp(neg_b, 0.5), half);
+        assert_quat_eq(Slerp(a).lerp(Slerp(neg_b), 0.5).0, half);
+    }
+
+    #[test]
+    fn from_mat4() {
+        let axes = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, -1.0, 0.0),
+            Vec3::new(1.0, 2.0, 3.0).normalized(),
+        ];
+        // cover all four branches of the extraction
+        for angle in [0.3f32, 2.0, 3.1] {
+            for axis in axes {
+                let q = Quaternion::axis_angle(axis, angle);
+                let m = Mat4::local_to_world(Vec3::new(1.0, 2.0, 3.0), q, Vec3::new(2.0, 3.0, 0.5));
+                let res = Quaternion::from_mat4(&m);
+                assert_same_rotation(res, q);
+                assert_same_rotation(Mat4::from(q).to_quaternion(), q);
+            }
+        }
+    }
+
+    #[test]
+    fn slerp() {
+        let axis = Vec3::new(1.0, 2.0, 3.0);
+        let a = Quaternion::axis_angle(axis, 0.2);
+        let b = Quaternion::axis_angle(axis, 2.2);
+
+        assert_quat_eq(Quaternion::slerp(a, b, 0.0), a);
+        assert_quat_eq(Quaternion::slerp(a, b, 1.0), b);
+        assert_quat_eq(
+            Quaternion::slerp(a, b, 0.25),
+            Quaternion::axis_angle(axis, 0.7),
+        );
+        assert_quat_eq(
+            Quaternion::slerp(a, b, 1.5),
+            Quaternion::axis_angle(axis, 3.2),
+        );
+
+        // nearly identical rotations fall back to nlerp
+        let c = Quaternion::axis_angle(axis, 0.2001);
+        assert!(Quaternion::slerp(a, c, 0.5).is_finite());
+    }
+
+    #[test]
+    fn nlerp() {
+        let axis = Vec3::new(0.0, 1.0, 0.0);
+        let a = Quaternion::axis_angle(axis, 0.2);
+        let b = Quaternion::axis_angle(axis, 0.6);
+
+        assert_quat_eq(Quaternion::nlerp(a, b, 0.0), a);
+        assert_quat_eq(Quaternion::nlerp(a, b, 1.0), b);
+        // symmetric interpolation is exact for nlerp as well
+        assert_quat_eq(
+            Quaternion::nlerp(a, b, 0.5),
+            Quaternion::axis_angle(axis, 0.4),
+        );
+
+        let neg_b = Quaternion::new(-b.x, -b.y, -b.z, -b.w);
+        assert_quat_eq(
+            Quaternion::nlerp(a, neg_b, 0.5),
+            Quaternion::axis_angle(axis, 0.4),
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let q = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+        assert_eq!(q.conjugate(), -q);
+        assert_quat_eq(q * q.conjugate(), Quaternion::IDENTITY);
+        assert_quat_eq(q.inverse(), q.conjugate());
+
+        let scaled = Quaternion::new(q.x * 2.0, q.y * 2.0, q.z * 2.0, q.w * 2.0);
+        assert_quat_eq(scaled * scaled.inverse(), Quaternion::IDENTITY);
+        assert_quat_eq(scaled.inverse() * scaled, Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn from_to_rotation() {
+        let cases = [
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0)),
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-3.0, 0.5, 1.0)),
+            (Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, -1.0, 0.0)),
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(-3.0, 0.0, 0.0)),
+        ];
+
+        for (from, to) in cases {
+            let q = Quaternion::from_to_rotation(from, to);
+            assert!((q * from.normalized() - to.normalized()).magnitude() < 1e-5);
+        }
+
+        let q = Quaternion::from_to_rotation(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        assert_quat_eq(
+            q,
+            Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), 90f32.to_radians()),
+        );
+    }
+
+    #[test]
+    fn to_axis_angle() {
+        let axis = Vec3::new(1.0, 2.0, 3.0).normalized();
+        for radians in [0.5, 2.0, 3.0] {
+            let (a, r) = Quaternion::axis_angle(axis, radians).to_axis_angle();
+            assert!((a - axis).magnitude() < 1e-5);
+            assert!((r - radians).abs() < 1e-5);
+        }
+
+        // angles above pi are returned as the shorter rotation around the negated axis
+        let (a, r) = Quaternion::axis_angle(axis, 4.0).to_axis_angle();
+        assert!((a + axis).magnitude() < 1e-5);
+        assert!((r - (std::f32::consts::TAU - 4.0)).abs() < 1e-5);
+
+        assert_eq!(
+            Quaternion::IDENTITY.to_axis_angle(),
+            (Vec3::new(1.0, 0.0, 0.0), 0.0)
+        );
+    }
+
+    #[test]
+    fn rotate_towards() {
+        let axis = Vec3::new(0.0, 1.0, 0.0);
+        let a = Quaternion::axis_angle(axis, 0.0);
+        let b = Quaternion::axis_angle(axis, 2.0);
+
+        assert_quat_eq(a.rotate_towards(b, 0.5), Quaternion::axis_angle(axis, 0.5));
+        assert_quat_eq(b.rotate_towards(a, 0.5), Quaternion::axis_angle(axis, 1.5));
+        assert_eq!(a.rotate_towards(b, 3.0), b);
+        assert_eq!(b.rotate_towards(b, 0.1), b);
+
+        // steps add up without overshooting
+        let mut q = a;
+        for _ in 0..5 {
+            q = q.rotate_towards(b, 0.5);
+        }
+        assert_eq!(q, b);
+    }
+
+    #[test]
+    fn swing_twist() {
+        let up = Vec3::new(0.0, 1.0, 0.0);
+        let twist = Quaternion::axis_angle(up, 0.8);
+        let swing = Quaternion::axis_angle(Vec3::new(1.0, 0.0, 2.0), 0.5);
+
+        let (s, t) = (swing * twist).swing_twist(up * 3.0);
+        assert_quat_eq(t, twist);
+        assert_quat_eq(s, swing);
+        assert_quat_eq(s * t, swing * twist);
+
+        let (s, t) = swing.swing_twist(up);
+        assert_quat_eq(t, Quaternion::IDENTITY);
+        assert_quat_eq(s, swing);
+
+        let flip = Quaternion::axis_angle(Vec3::new(1.0, 0.0, 0.0), std::f32::consts::PI);
+        let (s, t) = flip.swing_twist(up);
+        assert_quat_eq(t, Quaternion::IDENTITY);
+        assert_quat_eq(s, flip);
+    }
+
+    #[test]
+    fn blending() {
+        let axis = Vec3::new(0.0, 0.0, 1.0);
+        let a = Quaternion::axis_angle(axis, 0.2);
+        let b = Quaternion::axis_angle(axis, 0.6);
+
+        let blended = a * 0.5 + 0.5 * b;
+        let m = blended.dot(blended).sqrt();
+        assert_quat_eq(blended * (1.0 / m), Quaternion::nlerp(a, b, 0.5));
+
+        let mut acc = a;
+        acc *= 2.0;
+        acc += b;
+        assert_eq!(acc, a + a + b);
+    }
+
+    #[test]
+    fn normalization() {
+        let q = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+        assert!(q.is_normalized(1e-5));
+        assert!(!(q * 1.1).is_normalized(1e-5));
+        // the tolerance applies to the magnitude, not the squared magnitude
+        assert!((q * 1.015).is_normalized(0.02));
+        assert!(!(q * 1.015).is_normalized(0.01));
+
+        assert_quat_eq((q * 3.0).normalize_or_identity(), q);
+        assert_eq!(
+            Quaternion::new(0.0, 0.0, 0.0, 0.0).normalize_or_identity(),
+            Quaternion::IDENTITY
+        );
+        assert_eq!(
+            Quaternion::new(f32::NAN, 0.0, 0.0, 1.0).normalize_or_identity(),
+            Quaternion::IDENTITY
+        );
+    }
+
+    #[test]
+    fn from_rotation_axis() {
+        for radians in [0.5, -2.0] {
+            assert_quat_eq(
+                Quaternion::from_rotation_x(radians),
+                Quaternion::axis_angle(Vec3::new(1.0, 0.0, 0.0), radians),
+            );
+            assert_quat_eq(
+                Quaternion::from_rotation_y(radians),
+                Quaternion::axis_angle(Vec3::new(0.0, 1.0, 0.0), radians),
+            );
+            assert_quat_eq(
+                Quaternion::from_rotation_z(radians),
+                Quaternion::axis_angle(Vec3::new(0.0, 0.0, 1.0), radians),
+            );
+        }
+    }
+
+    #[test]
+    fn delta() {
+        let a = Quaternion::axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+        let b = Quaternion::axis_angle(Vec3::new(-1.0, 0.0, 2.0), 1.3);
+
+        assert_quat_eq(a.delta_to(b) * a, b);
+        assert_quat_eq(b / a * a, b);
+        assert_quat_eq(a / a, Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn scaled_axis() {
+        let v = Vec3::new(0.3, -0.5, 1.2);
+        let q = Quaternion::from_scaled_axis(v);
+        assert_quat_eq(q, Quaternion::axis_angle(v, v.magnitude()));
+        assert!((q.to_scaled_axis() - v).magnitude() < 1e-5);
+
+        assert_eq!(
+            Quaternion::from_scaled_axis(Vec3::ZERO),
+            Quaternion::IDENTITY
+        );
+        assert_eq!(Quaternion::IDENTITY.to_scaled_axis(), Vec3::ZERO);
+    }
+
+    #[test]
+    fn integrate() {
+        let axis = Vec3::new(0.0, 1.0, 0.0);
+        let start = Quaternion::axis_angle(Vec3::new(1.0, 0.0, 0.0), 0.3);
+
+        // 60 steps of 1/60s at 1 rad/s
+        let mut q = start;
+        for _ in 0..60 {
+            q = q.integrate(axis, 1.0 / 60.0);
+        }
+        assert_quat_eq(q, Quaternion::axis_angle(axis, 1.0) * start);
+        assert!(q.is_normalized(1e-6));
+    }
+
+    #[test]
+    fn array_conversions() {
+        let q = Quaternion::new(0.1, 0.2, 0.3, 0.9);
+        assert_eq!(q.to_array(), [0.1, 0.2, 0.3, 0.9]);
+        assert_eq!(Quaternion::from(<[f32; 4]>::from(q)), q);
+    }
+}
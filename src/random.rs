@@ -0,0 +1,164 @@
+use std::f32::consts::TAU;
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::{Quaternion, Vec2, Vec3, Vec4};
+
+/// Samples each component uniformly from \[0; 1)
+impl Distribution<Vec2> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        Vec2::new(rng.gen(), rng.gen())
+    }
+}
+
+/// Samples each component uniformly from \[0; 1)
+impl Distribution<Vec3> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        Vec3::new(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+/// Samples each component uniformly from \[0; 1)
+impl Distribution<Vec4> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4 {
+        Vec4::new(rng.gen(), rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+/// Samples a uniformly distributed rotation using Shoemake's method
+impl Distribution<Quaternion> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion {
+        let u1: f32 = rng.gen();
+        let (sin2, cos2) = (TAU * rng.gen::<f32>()).sin_cos();
+        let (sin3, cos3) = (TAU * rng.gen::<f32>()).sin_cos();
+
+        let a = (1.0 - u1).sqrt();
+        let b = u1.sqrt();
+        Quaternion::new(a * sin2, a * cos2, b * sin3, b * cos3)
+    }
+}
+
+/// Samples points uniformly on the unit circle, i.e. random 2D directions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitCircle;
+
+impl Distribution<Vec2> for UnitCircle {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        Vec2::from_angle(TAU * rng.gen::<f32>())
+    }
+}
+
+/// Samples points uniformly inside the unit disk
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitDisk;
+
+impl Distribution<Vec2> for UnitDisk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        UnitCircle.sample(rng) * rng.gen::<f32>().sqrt()
+    }
+}
+
+/// Samples points uniformly on the unit sphere, i.e. random 3D directions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitSphere;
+
+impl Distribution<Vec3> for UnitSphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let z = rng.gen_range(-1.0..=1.0f32);
+        let xy = UnitCircle.sample(rng) * (1.0 - z * z).max(0.0).sqrt();
+        xy.extend(z)
+    }
+}
+
+/// Samples points uniformly inside the unit ball
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitBall;
+
+impl Distribution<Vec3> for UnitBall {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        UnitSphere.sample(rng) * rng.gen::<f32>().cbrt()
+    }
+}
+
+/// Samples points uniformly inside the cube from (-1, -1, -1) to (1, 1, 1)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitCube;
+
+impl Distribution<Vec3> for UnitCube {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        Vec3::new(
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+            rng.gen_range(-1.0..1.0),
+        )
+    }
+}
+
+/// Samples unit directions in the hemisphere around +z,
+/// with a probability density proportional to the cosine of the angle to +z.
+///
+/// This is the importance sampling distribution for diffuse (Lambertian) surfaces.
+/// Rotate the samples into place for other normals,
+/// e.g. with [`Quaternion::from_to_rotation()`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CosineHemisphere;
+
+impl Distribution<Vec3> for CosineHemisphere {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // Malley's method: project points on the unit disk up onto the hemisphere
+        let p = UnitDisk.sample(rng);
+        p.extend((1.0 - p.sqr_magnitude()).max(0.0).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn uniform_rotations() {
+        let mut rng = SmallRng::seed_from_u64(42);
+
+        // rotated directions of a uniform distribution average out to zero
+        let mut sum = Vec3::ZERO;
+        for _ in 0..10000 {
+            let q: Quaternion = rng.gen();
+            assert!(q.is_normalized(1e-5));
+            sum += q.forward();
+        }
+        assert!((sum / 10000.0).magnitude() < 0.05);
+
+        let v: Vec3 = rng.gen();
+        assert!([v.x, v.y, v.z].iter().all(|c| (0.0..1.0).contains(c)));
+    }
+
+    #[test]
+    fn point_sampling() {
+        let mut rng = SmallRng::seed_from_u64(7);
+
+        let mut sphere_sum = Vec3::ZERO;
+        let mut hemisphere_z = 0.0;
+        for _ in 0..10000 {
+            assert!((rng.sample(UnitCircle).magnitude() - 1.0).abs() < 1e-5);
+            assert!(rng.sample(UnitDisk).magnitude() <= 1.0);
+            assert!(rng.sample(UnitBall).magnitude() <= 1.0);
+            assert!(rng.sample(UnitCube).max_element() < 1.0);
+
+            let dir = rng.sample(UnitSphere);
+            assert!((dir.magnitude() - 1.0).abs() < 1e-5);
+            sphere_sum += dir;
+
+            let h = rng.sample(CosineHemisphere);
+            assert!((h.magnitude() - 1.0).abs() < 1e-5 && h.z >= 0.0);
+            hemisphere_z += h.z;
+        }
+
+        assert!((sphere_sum / 10000.0).magnitude() < 0.05);
+        // the mean cosine of a cosine weighted hemisphere is 2/3
+        assert!((hemisphere_z / 10000.0 - 2.0 / 3.0).abs() < 0.02);
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use crate::Vec2;
+
+/// An axis aligned 2D rectangle, e.g. a viewport
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Display for Rect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            x,
+            y,
+            width,
+            height,
+        } = self;
+        write!(f, "({x}, {y}, {width}, {height})")
+    }
+}
+
+impl Rect {
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the position of the rectangle's origin corner
+    pub const fn position(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    /// Returns the rectangle's width and height
+    pub const fn size(&self) -> Vec2 {
+        Vec2::new(self.width, self.height)
+    }
+
+    /// Returns the largest rectangle with the aspect ratio `aspect` (width / height)
+    /// that fits into a container of size `container`, centered inside it.
+    ///
+    /// The remaining space forms horizontal (letterbox) or vertical (pillarbox) bars.
+    pub fn aspect_fit(container: Vec2, aspect: f32) -> Self {
+        let (width, height) = if container.x / container.y > aspect {
+            // container is wider, pillarbox
+            (container.y * aspect, container.y)
+        } else {
+            // container is taller, letterbox
+            (container.x, container.x / aspect)
+        };
+
+        Self {
+            x: (container.x - width) * 0.5,
+            y: (container.y - height) * 0.5,
+            width,
+            height,
+        }
+    }
+
+    /// Like [`aspect_fit()`](Self::aspect_fit()), but fits content of size `content` into `container`.
+    ///
+    /// Also returns the factor by which the content has to be scaled to fill the resulting rectangle.
+    pub fn aspect_fit_scaled(container: Vec2, content: Vec2) -> (Self, f32) {
+        let rect = Self::aspect_fit(container, content.x / content.y);
+        (rect, rect.width / content.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aspect_fit() {
+        assert_eq!(
+            Rect::aspect_fit(Vec2::new(1920.0, 1080.0), 4.0 / 3.0),
+            Rect::new(240.0, 0.0, 1440.0, 1080.0)
+        );
+        assert_eq!(
+            Rect::aspect_fit(Vec2::new(1000.0, 1000.0), 2.0),
+            Rect::new(0.0, 250.0, 1000.0, 500.0)
+        );
+
+        let (rect, scale) =
+            Rect::aspect_fit_scaled(Vec2::new(1920.0, 1200.0), Vec2::new(320.0, 180.0));
+        assert_eq!(rect, Rect::new(0.0, 60.0, 1920.0, 1080.0));
+        assert_eq!(scale, 6.0);
+    }
+}
@@ -0,0 +1,38 @@
+use std::f32::consts::{PI, TAU};
+
+/// Wraps `x` into the range \[`min`; `max`), e.g. for tiling worlds or repeating texture coordinates.
+///
+/// Unlike clamping, values leaving the range on one side re-enter it on the other side.
+pub fn wrap(x: f32, min: f32, max: f32) -> f32 {
+    min + (x - min).rem_euclid(max - min)
+}
+
+/// Wraps the angle `radians` into the range \[-π; π), e.g. to get the shortest turn from an angle delta
+pub fn wrap_angle(radians: f32) -> f32 {
+    let wrapped = (radians + PI).rem_euclid(TAU) - PI;
+    // rem_euclid may round up to TAU for tiny negative inputs
+    if wrapped >= PI {
+        wrapped - TAU
+    } else {
+        wrapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap() {
+        assert_eq!(super::wrap(5.5, 0.0, 5.0), 0.5);
+        assert_eq!(super::wrap(-0.5, 0.0, 5.0), 4.5);
+        assert_eq!(super::wrap(5.0, 0.0, 5.0), 0.0);
+        assert_eq!(super::wrap(-3.0, -2.0, 2.0), 1.0);
+
+        assert!((wrap_angle(3.0 * PI / 2.0) + PI / 2.0).abs() < 1e-6);
+        assert!((wrap_angle(-3.0 * PI / 2.0) - PI / 2.0).abs() < 1e-6);
+        assert!((wrap_angle(0.5 + 4.0 * TAU) - 0.5).abs() < 1e-5);
+        assert_eq!(wrap_angle(PI), -PI);
+        assert!((-PI..PI).contains(&wrap_angle(-1e-9)));
+    }
+}
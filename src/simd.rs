@@ -0,0 +1,81 @@
+//! SIMD implementations of the hot matrix products.
+//!
+//! The functions operate on the raw storage of a [`Mat4`](crate::Mat4) as four lanes of four values,
+//! which are the columns in column-major order or the rows in row-major order.
+//! SSE and NEON are part of the baseline of x86_64 and aarch64 respectively,
+//! so no runtime feature detection is needed. Other targets use a scalar fallback.
+
+/// Returns `b[0] * a.lane(0) + b[1] * a.lane(1) + b[2] * a.lane(2) + b[3] * a.lane(3)`
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn combine_lanes(a: &[f32; 16], b: [f32; 4]) -> [f32; 4] {
+    use std::arch::x86_64::*;
+
+    let mut res = [0.0; 4];
+    // SAFETY: SSE is always available on x86_64 and all loads and stores are unaligned and in bounds
+    unsafe {
+        let p = a.as_ptr();
+        let mut acc = _mm_mul_ps(_mm_loadu_ps(p), _mm_set1_ps(b[0]));
+        acc = _mm_add_ps(acc, _mm_mul_ps(_mm_loadu_ps(p.add(4)), _mm_set1_ps(b[1])));
+        acc = _mm_add_ps(acc, _mm_mul_ps(_mm_loadu_ps(p.add(8)), _mm_set1_ps(b[2])));
+        acc = _mm_add_ps(acc, _mm_mul_ps(_mm_loadu_ps(p.add(12)), _mm_set1_ps(b[3])));
+        _mm_storeu_ps(res.as_mut_ptr(), acc);
+    }
+    res
+}
+
+/// Returns `b[0] * a.lane(0) + b[1] * a.lane(1) + b[2] * a.lane(2) + b[3] * a.lane(3)`
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn combine_lanes(a: &[f32; 16], b: [f32; 4]) -> [f32; 4] {
+    use std::arch::aarch64::*;
+
+    let mut res = [0.0; 4];
+    // SAFETY: NEON is always available on aarch64 and all loads and stores are in bounds
+    unsafe {
+        let p = a.as_ptr();
+        let mut acc = vmulq_n_f32(vld1q_f32(p), b[0]);
+        acc = vfmaq_n_f32(acc, vld1q_f32(p.add(4)), b[1]);
+        acc = vfmaq_n_f32(acc, vld1q_f32(p.add(8)), b[2]);
+        acc = vfmaq_n_f32(acc, vld1q_f32(p.add(12)), b[3]);
+        vst1q_f32(res.as_mut_ptr(), acc);
+    }
+    res
+}
+
+/// Returns `b[0] * a.lane(0) + b[1] * a.lane(1) + b[2] * a.lane(2) + b[3] * a.lane(3)`
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) fn combine_lanes(a: &[f32; 16], b: [f32; 4]) -> [f32; 4] {
+    let mut res = [0.0; 4];
+    for (i, v) in res.iter_mut().enumerate() {
+        *v = a[i] * b[0] + a[4 + i] * b[1] + a[8 + i] * b[2] + a[12 + i] * b[3];
+    }
+    res
+}
+
+/// Multiplies two matrices given as lanes,
+/// i.e. `a * b` for column-major and `b * a` for row-major storage
+pub(crate) fn mul_lanes(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let mut res = [0.0; 16];
+    for (res, b) in res.chunks_exact_mut(4).zip(b.chunks_exact(4)) {
+        res.copy_from_slice(&combine_lanes(a, [b[0], b[1], b[2], b[3]]));
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_lanes_matches_scalar() {
+        let a: [f32; 16] = std::array::from_fn(|i| i as f32 * 0.5 - 3.0);
+        let b: [f32; 16] = std::array::from_fn(|i| (i * i) as f32 * 0.25 + 1.0);
+
+        let res = mul_lanes(&a, &b);
+        for lane in 0..4 {
+            for i in 0..4 {
+                let expected: f32 = (0..4).map(|k| a[k * 4 + i] * b[lane * 4 + k]).sum();
+                assert_eq!(res[lane * 4 + i], expected);
+            }
+        }
+    }
+}
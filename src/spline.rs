@@ -0,0 +1,91 @@
+use crate::VectorSpace;
+
+/// Splits the curve parameter `t` in \[0; 1\] into a segment index and the local parameter
+fn bspline_segment(num_points: usize, t: f32) -> (usize, f32) {
+    assert!(
+        num_points >= 4,
+        "a cubic B-spline needs at least 4 control points, got {num_points}"
+    );
+
+    let segments = num_points - 3;
+    let t = t.clamp(0.0, 1.0) * segments as f32;
+    let i = (t as usize).min(segments - 1);
+
+    (i, t - i as f32)
+}
+
+/// Evaluates the uniform cubic B-spline with the given control points at `t`.
+///
+/// `t` is clamped to \[0; 1\] and spans all `points.len() - 3` segments of the curve.
+/// Unlike Bezier or Catmull-Rom curves, the curve does not pass through its control points.
+///
+/// # Panics
+/// Panics if fewer than 4 control points are given.
+pub fn bspline<V: VectorSpace>(points: &[V], t: f32) -> V {
+    let (i, t) = bspline_segment(points.len(), t);
+    let p = &points[i..i + 4];
+
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let it = 1.0 - t;
+
+    let b0 = it * it * it;
+    let b1 = 3.0 * t3 - 6.0 * t2 + 4.0;
+    let b2 = -3.0 * t3 + 3.0 * t2 + 3.0 * t + 1.0;
+    let b3 = t3;
+
+    (p[0] * b0 + p[1] * b1 + p[2] * b2 + p[3] * b3) / 6.0
+}
+
+/// Evaluates the derivative of [`bspline()`] with respect to `t`.
+///
+/// # Panics
+/// Panics if fewer than 4 control points are given.
+pub fn bspline_derivative<V: VectorSpace>(points: &[V], t: f32) -> V {
+    let (i, t) = bspline_segment(points.len(), t);
+    let p = &points[i..i + 4];
+
+    let t2 = t * t;
+    let it = 1.0 - t;
+
+    let b0 = -it * it;
+    let b1 = 3.0 * t2 - 4.0 * t;
+    let b2 = -3.0 * t2 + 2.0 * t + 1.0;
+    let b3 = t2;
+
+    // chain rule, the global parameter spans all segments
+    let segments = (points.len() - 3) as f32;
+    (p[0] * b0 + p[1] * b1 + p[2] * b2 + p[3] * b3) * (0.5 * segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3};
+
+    #[test]
+    fn bspline_eval() {
+        let line = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(4.0, 0.0),
+        ];
+
+        assert_eq!(bspline(&line, 0.0), Vec2::new(1.0, 0.0));
+        assert_eq!(bspline(&line, 0.5), Vec2::new(2.0, 0.0));
+        assert_eq!(bspline(&line, 1.0), Vec2::new(3.0, 0.0));
+        assert_eq!(bspline_derivative(&line, 0.25), Vec2::new(2.0, 0.0));
+
+        let curve = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(0.0, 6.0, 0.0),
+            Vec3::new(6.0, 6.0, 0.0),
+            Vec3::new(6.0, 0.0, 6.0),
+        ];
+        assert_eq!(bspline(&curve, 0.0), Vec3::new(1.0, 5.0, 0.0));
+        assert_eq!(bspline(&curve, 1.0), Vec3::new(5.0, 5.0, 1.0));
+        assert_eq!(bspline_derivative(&curve, 0.0), Vec3::new(3.0, 3.0, 0.0));
+    }
+}
@@ -0,0 +1,201 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Vec2, Vec3, Vec4};
+
+/// A type whose values can be added, subtracted and scaled by an `f32`.
+///
+/// Implemented by [`f32`], [`Vec2`], [`Vec3`] and [`Vec4`], so that
+/// interpolators, splines and solvers can be written once for all dimensions.
+pub trait VectorSpace:
+    Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f32, Output = Self>
+    + Div<f32, Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity
+    const ZERO: Self;
+}
+
+/// A [`VectorSpace`] with an inner (dot) product
+pub trait InnerSpace: VectorSpace {
+    /// Returns the dot product of `self` and `b`
+    fn dot(&self, b: Self) -> f32;
+
+    /// Returns the square of the vector's length.
+    ///
+    /// Faster to compute than [`magnitude()`](Self::magnitude())
+    fn sqr_magnitude(&self) -> f32 {
+        self.dot(*self)
+    }
+
+    /// Returns the vector's length
+    fn magnitude(&self) -> f32 {
+        self.sqr_magnitude().sqrt()
+    }
+
+    /// Returns a normalized copy of `self`
+    #[must_use]
+    fn normalized(&self) -> Self {
+        *self / self.magnitude()
+    }
+}
+
+/// A type with a cross product
+pub trait Cross {
+    type Output;
+
+    /// Returns the cross product of `self` and `b`
+    fn cross(&self, b: Self) -> Self::Output;
+}
+
+/// A type that can be linearly interpolated
+pub trait Lerp: Sized {
+    /// Interpolates between `self` (`t = 0`) and `b` (`t = 1`).
+    ///
+    /// `t` is not clamped, so values outside of \[0; 1\] extrapolate.
+    fn lerp_unclamped(&self, b: Self, t: f32) -> Self;
+
+    /// Interpolates between `self` (`t = 0`) and `b` (`t = 1`),
+    /// with `t` clamped to \[0; 1\].
+    fn lerp(&self, b: Self, t: f32) -> Self {
+        self.lerp_unclamped(b, t.clamp(0.0, 1.0))
+    }
+}
+
+impl<V: VectorSpace> Lerp for V {
+    fn lerp_unclamped(&self, b: Self, t: f32) -> Self {
+        *self + (b - *self) * t
+    }
+}
+
+/// Returns the centroid (average) of `points`.
+///
+/// For the center of mass of a polygon's area, see [`polygon_centroid()`](crate::polygon_centroid()).
+/// Returns NaN components if `points` is empty.
+pub fn centroid<V: VectorSpace>(points: &[V]) -> V {
+    points.iter().fold(V::ZERO, |acc, p| acc + *p) / points.len() as f32
+}
+
+impl VectorSpace for f32 {
+    const ZERO: Self = 0.0;
+}
+
+impl InnerSpace for f32 {
+    fn dot(&self, b: Self) -> f32 {
+        self * b
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.abs()
+    }
+}
+
+macro_rules! impl_inner_space {
+    ($t:ident) => {
+        impl VectorSpace for $t {
+            const ZERO: Self = $t::ZERO;
+        }
+
+        impl InnerSpace for $t {
+            fn dot(&self, b: Self) -> f32 {
+                $t::dot(self, b)
+            }
+
+            fn sqr_magnitude(&self) -> f32 {
+                $t::sqr_magnitude(self)
+            }
+
+            fn magnitude(&self) -> f32 {
+                $t::magnitude(self)
+            }
+
+            fn normalized(&self) -> Self {
+                $t::normalized(self)
+            }
+        }
+    };
+}
+
+impl_inner_space!(Vec2);
+impl_inner_space!(Vec3);
+impl_inner_space!(Vec4);
+
+/// The 2D cross product, i.e. the z component of the cross product of `self` and `b` extended to 3D
+impl Cross for Vec2 {
+    type Output = f32;
+
+    fn cross(&self, b: Self) -> f32 {
+        self.perp_dot(b)
+    }
+}
+
+impl Cross for Vec3 {
+    type Output = Vec3;
+
+    fn cross(&self, b: Self) -> Vec3 {
+        Vec3::cross(self, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project<V: InnerSpace>(a: V, b: V) -> V {
+        b * (a.dot(b) / b.sqr_magnitude())
+    }
+
+    #[test]
+    fn generic() {
+        assert_eq!(
+            project(Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0)),
+            Vec2::new(1.0, 0.0)
+        );
+        assert_eq!(
+            project(Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.0, 0.0, 5.0)),
+            Vec3::new(0.0, 0.0, 3.0)
+        );
+        assert_eq!(project(3.0f32, -2.0), 3.0);
+        assert_eq!(InnerSpace::magnitude(&-2.0f32), 2.0);
+        assert_eq!(<Vec4 as VectorSpace>::ZERO, Vec4::ZERO);
+
+        assert_eq!(Cross::cross(&Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)), 1.0);
+        assert_eq!(
+            Cross::cross(&Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+            Vec3::new(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        assert_eq!(1.0f32.lerp(3.0, 0.5), 2.0);
+        assert_eq!(1.0f32.lerp(3.0, 2.0), 3.0);
+        assert_eq!(1.0f32.lerp_unclamped(3.0, 2.0), 5.0);
+
+        assert_eq!(
+            Vec2::ZERO.lerp(Vec2::new(2.0, 4.0), 0.25),
+            Vec2::new(0.5, 1.0)
+        );
+        assert_eq!(Vec3::ONE.lerp(Vec3::ZERO, -1.0), Vec3::ONE);
+        assert_eq!(Vec4::ZERO.lerp_unclamped(Vec4::ONE, -1.0), -Vec4::ONE);
+    }
+
+    #[test]
+    fn centroid() {
+        let points = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(1.0, 1.0, 4.0),
+        ];
+        assert_eq!(super::centroid(&points), Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(super::centroid(&[1.0f32, 2.0, 6.0]), 3.0);
+        assert_eq!(
+            Vec2::midpoint(Vec2::new(-1.0, 2.0), Vec2::new(3.0, 4.0)),
+            Vec2::new(1.0, 3.0)
+        );
+    }
+}
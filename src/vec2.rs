@@ -25,10 +25,51 @@ impl Vec2 {
     /// The one vector (1, 1)
     pub const ONE: Self = Self::new(1.0, 1.0);
 
+    /// The unit vector along the positive x axis (1, 0)
+    pub const X: Self = Self::new(1.0, 0.0);
+    /// The unit vector along the positive y axis (0, 1)
+    pub const Y: Self = Self::new(0.0, 1.0);
+    /// The unit vector along the negative x axis (-1, 0)
+    pub const NEG_X: Self = Self::new(-1.0, 0.0);
+    /// The unit vector along the negative y axis (0, -1)
+    pub const NEG_Y: Self = Self::new(0.0, -1.0);
+
     pub const fn new(x: f32, y: f32) -> Self {
         Self { x, y }
     }
 
+    /// Creates a vector with all components set to `v`
+    pub const fn splat(v: f32) -> Self {
+        Self::new(v, v)
+    }
+
+    /// Creates the unit vector pointing at `radians` counter-clockwise from the positive x axis
+    pub fn from_angle(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    /// Creates a vector from the polar coordinates `r` (distance from the origin)
+    /// and `theta` (angle in radians counter-clockwise from the positive x axis)
+    pub fn from_polar(r: f32, theta: f32) -> Self {
+        Self::from_angle(theta) * r
+    }
+
+    /// Returns the components of `self` as an array, in the order x, y
+    pub const fn to_array(&self) -> [f32; 2] {
+        [self.x, self.y]
+    }
+
+    /// Returns the raw bit patterns of the components of `self`, see [`f32::to_bits()`]
+    pub fn to_bits(&self) -> [u32; 2] {
+        [self.x.to_bits(), self.y.to_bits()]
+    }
+
+    /// Returns `true` if all components of `self` are neither infinite nor NaN
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
     /// Returns the square of the vector's length.
     ///
     /// Faster to compute than [`magnitude()`](Self::magnitude())
@@ -41,11 +82,17 @@ impl Vec2 {
         self.sqr_magnitude().sqrt()
     }
 
+    /// Returns the reciprocal of the vector's length, `1 / magnitude()`
+    pub fn magnitude_recip(&self) -> f32 {
+        self.magnitude().recip()
+    }
+
     /// Normalizes `self` in place
     pub fn normalize(&mut self) -> &mut Self {
         let m = self.magnitude();
         self.x /= m;
         self.y /= m;
+        debug_validate!("Vec2::normalize", self, m);
         self
     }
     /// Returns a normalized copy of `self`
@@ -54,11 +101,261 @@ impl Vec2 {
         *self.clone().normalize()
     }
 
+    /// Returns a normalized copy of `self`, or `None` if `self` has zero, infinite or NaN length
+    #[must_use]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let r = self.magnitude_recip();
+        if r.is_finite() && r > 0.0 {
+            Some(*self * r)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a normalized copy of `self`, or `fallback` if `self` can not be normalized.
+    ///
+    /// See [`try_normalize()`](Self::try_normalize()).
+    #[must_use]
+    pub fn normalize_or(&self, fallback: Vec2) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a normalized copy of `self`, or zero if `self` can not be normalized.
+    ///
+    /// See [`try_normalize()`](Self::try_normalize()).
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    /// Linearly interpolates between `self` (`t = 0`) and `b` (`t = 1`),
+    /// with `t` clamped to \[0; 1\]
+    pub fn lerp(&self, b: Vec2, t: f32) -> Self {
+        self.lerp_unclamped(b, t.clamp(0.0, 1.0))
+    }
+
+    /// Linearly interpolates between `self` (`t = 0`) and `b` (`t = 1`).
+    ///
+    /// `t` is not clamped, so values outside of \[0; 1\] extrapolate.
+    pub fn lerp_unclamped(&self, b: Vec2, t: f32) -> Self {
+        *self + (b - *self) * t
+    }
+
+    /// Returns the point halfway between `a` and `b`
+    pub fn midpoint(a: Vec2, b: Vec2) -> Self {
+        (a + b) * 0.5
+    }
+
+    /// Reflects the incident vector `self` off a surface with the normal `normal`, like GLSL's `reflect()`.
+    ///
+    /// `self` points towards the surface and the result points away from it.
+    /// `normal` has to be normalized.
+    pub fn reflect(&self, normal: Vec2) -> Self {
+        *self - normal * (2.0 * normal.dot(*self))
+    }
+
+    /// Refracts the incident vector `self` at a surface with the normal `normal`, like GLSL's `refract()`.
+    ///
+    /// `eta` is the ratio of the indices of refraction (incident / transmitted).
+    /// `self` and `normal` have to be normalized.
+    /// Returns `None` in case of total internal reflection.
+    pub fn refract(&self, normal: Vec2, eta: f32) -> Option<Self> {
+        let d = normal.dot(*self);
+        let k = 1.0 - eta * eta * (1.0 - d * d);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta - normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// Returns the projection of `self` onto `other`, i.e. the component of `self` parallel to `other`
+    pub fn project_onto(&self, other: Vec2) -> Self {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Like [`project_onto()`](Self::project_onto()), but `other` has to be normalized
+    pub fn project_onto_normalized(&self, other: Vec2) -> Self {
+        other * self.dot(other)
+    }
+
+    /// Returns the rejection of `self` from `other`, i.e. the component of `self` perpendicular to `other`
+    pub fn reject_from(&self, other: Vec2) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    /// Returns the componentwise absolute value of `self`
+    pub fn abs(&self) -> Self {
+        Vec2::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Returns the componentwise sign of `self`, see [`f32::signum()`]
+    pub fn signum(&self) -> Self {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Returns the componentwise floor of `self`, i.e. the largest integers less than or equal to the components
+    pub fn floor(&self) -> Self {
+        Vec2::new(self.x.floor(), self.y.floor())
+    }
+
+    /// Returns the componentwise ceiling of `self`, i.e. the smallest integers greater than or equal to the components
+    pub fn ceil(&self) -> Self {
+        Vec2::new(self.x.ceil(), self.y.ceil())
+    }
+
+    /// Returns `self` with each component rounded to the nearest integer, half-way cases away from zero
+    pub fn round(&self) -> Self {
+        Vec2::new(self.x.round(), self.y.round())
+    }
+
+    /// Returns the fractional part of each component, i.e. `self - self.floor()` like GLSL's `fract()`.
+    ///
+    /// Unlike [`f32::fract()`], the result is never negative.
+    pub fn fract(&self) -> Self {
+        *self - self.floor()
+    }
+
+    /// Returns `self` with every component raised to the power of `n`, see [`f32::powf()`]
+    pub fn powf(&self, n: f32) -> Self {
+        Vec2::new(self.x.powf(n), self.y.powf(n))
+    }
+
+    /// Returns the componentwise square root of `self`
+    pub fn sqrt(&self) -> Self {
+        Vec2::new(self.x.sqrt(), self.y.sqrt())
+    }
+
+    /// Returns the componentwise exponential `e^self`
+    pub fn exp(&self) -> Self {
+        Vec2::new(self.x.exp(), self.y.exp())
+    }
+
+    /// Returns the componentwise natural logarithm of `self`
+    pub fn ln(&self) -> Self {
+        Vec2::new(self.x.ln(), self.y.ln())
+    }
+
+    /// Returns the componentwise reciprocal `1 / self`
+    pub fn recip(&self) -> Self {
+        Vec2::new(self.x.recip(), self.y.recip())
+    }
+
+    /// Returns the componentwise minimum of `self` and `b`
+    pub fn min(&self, b: Vec2) -> Self {
+        Vec2::new(self.x.min(b.x), self.y.min(b.y))
+    }
+
+    /// Returns the componentwise maximum of `self` and `b`
+    pub fn max(&self, b: Vec2) -> Self {
+        Vec2::new(self.x.max(b.x), self.y.max(b.y))
+    }
+
+    /// Clamps each component of `self` to the range given by the corresponding components of `min` and `max`
+    pub fn clamp(&self, min: Vec2, max: Vec2) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Wraps each component of `self` into the range given by the corresponding components of `min` and `max`,
+    /// see [`wrap()`](crate::wrap())
+    pub fn wrap(&self, min: Vec2, max: Vec2) -> Self {
+        Vec2::new(
+            crate::wrap(self.x, min.x, max.x),
+            crate::wrap(self.y, min.y, max.y),
+        )
+    }
+
+    /// Wraps each component of `self` into the range \[-π; π), see [`wrap_angle()`](crate::wrap_angle())
+    pub fn wrap_angle(&self) -> Self {
+        Vec2::new(crate::wrap_angle(self.x), crate::wrap_angle(self.y))
+    }
+
+    /// Returns the smallest component of `self`
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y)
+    }
+
+    /// Returns the largest component of `self`
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y)
+    }
+
+    /// Returns the sum of all components of `self`
+    pub fn element_sum(&self) -> f32 {
+        self.x + self.y
+    }
+
+    /// Returns the product of all components of `self`
+    pub fn element_product(&self) -> f32 {
+        self.x * self.y
+    }
+
+    /// Returns an iterator over the components of `self`, in the order x, y
+    pub fn iter(&self) -> std::array::IntoIter<f32, 2> {
+        self.to_array().into_iter()
+    }
+
     /// Returns the dot product of `self` and `b`
     pub fn dot(&self, b: Vec2) -> f32 {
         self.x * b.x + self.y * b.y
     }
 
+    /// Returns `self` rotated by 90° counter-clockwise, i.e. `(-y, x)`
+    pub const fn perp(&self) -> Self {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Returns the perpendicular dot product `self.perp().dot(b)`, the 2D analogue of the cross product.
+    ///
+    /// Positive if `b` is counter-clockwise from `self`, negative if clockwise and zero if both are parallel.
+    pub fn perp_dot(&self, b: Vec2) -> f32 {
+        self.x * b.y - self.y * b.x
+    }
+
+    /// Returns the 2D cross product, i.e. the z component of the cross product of `self` and `b` extended to 3D.
+    ///
+    /// Same as [`perp_dot()`](Self::perp_dot()).
+    pub fn cross(&self, b: Vec2) -> f32 {
+        self.perp_dot(b)
+    }
+
+    /// Returns the angle of `self` in radians, counter-clockwise from the positive x axis, in \[-π; π\]
+    pub fn to_angle(&self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    /// Returns the unsigned angle between `self` and `other` in radians, in \[0; π\]
+    pub fn angle_to(&self, other: Vec2) -> f32 {
+        self.signed_angle_to(other).abs()
+    }
+
+    /// Returns the angle in radians by which `self` has to be rotated counter-clockwise
+    /// to point in the direction of `other`, in \[-π; π\]
+    pub fn signed_angle_to(&self, other: Vec2) -> f32 {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+
+    /// Returns the polar coordinates `(r, theta)` of `self`, see [`from_polar()`](Self::from_polar())
+    pub fn to_polar(&self) -> (f32, f32) {
+        (self.magnitude(), self.to_angle())
+    }
+
+    /// Returns `self` rotated counter-clockwise by `radians`
+    pub fn rotate(&self, radians: f32) -> Self {
+        self.rotate_by(Self::from_angle(radians))
+    }
+
+    /// Returns `self` rotated by `rotation`, a unit vector holding the cosine and sine of the angle.
+    ///
+    /// Cheaper than [`rotate()`](Self::rotate()) when rotating many vectors by the same angle,
+    /// see [`from_angle()`](Self::from_angle()).
+    pub fn rotate_by(&self, rotation: Vec2) -> Self {
+        Vec2::new(
+            self.x * rotation.x - self.y * rotation.y,
+            self.x * rotation.y + self.y * rotation.x,
+        )
+    }
+
     pub fn extend(&self, z: f32) -> Vec3 {
         Vec3 {
             x: self.x,
@@ -66,6 +363,33 @@ impl Vec2 {
             z,
         }
     }
+
+    /// Returns `self` with every component rounded to the nearest multiple of `step`
+    #[must_use]
+    pub fn snap_to_grid(&self, step: f32) -> Self {
+        Self {
+            x: (self.x / step).round() * step,
+            y: (self.y / step).round() * step,
+        }
+    }
+
+    /// Quantizes `self` to integer multiples of `step`.
+    ///
+    /// The original vector can be approximately restored with [`dequantize()`](Self::dequantize())
+    pub fn quantize(&self, step: f32) -> [i32; 2] {
+        [
+            (self.x / step).round() as i32,
+            (self.y / step).round() as i32,
+        ]
+    }
+
+    /// Restores a vector that was quantized by [`quantize()`](Self::quantize()) with the same `step`
+    pub fn dequantize(q: [i32; 2], step: f32) -> Self {
+        Self {
+            x: q[0] as f32 * step,
+            y: q[1] as f32 * step,
+        }
+    }
 }
 
 /// Vec2 swizzles
@@ -102,13 +426,16 @@ impl Vec2 {
     swizzle!(y, y, y, y);
 }
 
+byte_conversions!(Vec2, x, y);
+norm_conversions!(Vec2, x, y);
+
 impl_op_ex!(+= |a: &mut Vec2, b: &Vec2| { a.x += b.x; a.y += b.y; });
 impl_op_ex!(-= |a: &mut Vec2, b: &Vec2| { a.x -= b.x; a.y -= b.y; });
 impl_op_ex!(*= |a: &mut Vec2, b: &Vec2| { a.x *= b.x; a.y *= b.y; });
-impl_op_ex!(/= |a: &mut Vec2, b: &Vec2| { a.x /= b.x; a.y /= b.y; });
+impl_op_ex!(/= |a: &mut Vec2, b: &Vec2| { a.x /= b.x; a.y /= b.y; debug_validate!("Vec2 /= Vec2", a, b); });
 
 impl_op_ex!(*= |a: &mut Vec2, b: &f32| { a.x *= b; a.y *= b });
-impl_op_ex!(/= |a: &mut Vec2, b: &f32| { a.x /= b; a.y /= b });
+impl_op_ex!(/= |a: &mut Vec2, b: &f32| { a.x /= b; a.y /= b; debug_validate!("Vec2 /= f32", a, b); });
 
 impl_op_ex!(+ |a: &Vec2, b: &Vec2| -> Vec2 { Vec2{x: a.x + b.x, y: a.y + b.y} });
 impl_op_ex!(-|a: &Vec2, b: &Vec2| -> Vec2 {
@@ -123,7 +450,7 @@ impl_op_ex!(*|a: &Vec2, b: &Vec2| -> Vec2 {
         y: a.y * b.y,
     }
 });
-impl_op_ex!(/ |a: &Vec2, b: &Vec2| -> Vec2 { Vec2{x: a.x / b.x, y: a.y / b.y} });
+impl_op_ex!(/ |a: &Vec2, b: &Vec2| -> Vec2 { let res = Vec2{x: a.x / b.x, y: a.y / b.y}; debug_validate!("Vec2 / Vec2", res, a, b); res });
 
 impl_op_ex_commutative!(*|a: &Vec2, b: &f32| -> Vec2 {
     Vec2 {
@@ -131,8 +458,8 @@ impl_op_ex_commutative!(*|a: &Vec2, b: &f32| -> Vec2 {
         y: a.y * b,
     }
 });
-impl_op_ex!(/ |a: &Vec2, b: &f32| -> Vec2 { Vec2{x: a.x / b, y: a.y / b} });
-impl_op_ex!(/ |a: &f32, b: &Vec2| -> Vec2 { Vec2{x: a / b.x, y: a / b.y} });
+impl_op_ex!(/ |a: &Vec2, b: &f32| -> Vec2 { let res = Vec2{x: a.x / b, y: a.y / b}; debug_validate!("Vec2 / f32", res, a, b); res });
+impl_op_ex!(/ |a: &f32, b: &Vec2| -> Vec2 { let res = Vec2{x: a / b.x, y: a / b.y}; debug_validate!("f32 / Vec2", res, a, b); res });
 
 impl Neg for Vec2 {
     type Output = Vec2;
@@ -150,6 +477,68 @@ impl From<[f32; 2]> for Vec2 {
     }
 }
 
+impl From<Vec2> for [f32; 2] {
+    fn from(v: Vec2) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(f32, f32)> for Vec2 {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Vec2> for (f32, f32) {
+    fn from(v: Vec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl std::iter::Sum<Vec2> for Vec2 {
+    fn sum<I: Iterator<Item = Vec2>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Vec2> for Vec2 {
+    fn sum<I: Iterator<Item = &'a Vec2>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+/// Multiplies componentwise
+impl std::iter::Product<Vec2> for Vec2 {
+    fn product<I: Iterator<Item = Vec2>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+/// Multiplies componentwise
+impl<'a> std::iter::Product<&'a Vec2> for Vec2 {
+    fn product<I: Iterator<Item = &'a Vec2>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl IntoIterator for Vec2 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Vec2 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +603,121 @@ mod tests {
         c /= 2.0;
         assert_eq!(c, a / 2.0);
     }
+
+    #[test]
+    fn snapping() {
+        let a = Vec2::new(1.2, -3.7);
+
+        assert_eq!(a.snap_to_grid(0.5), Vec2::new(1.0, -3.5));
+        assert_eq!(a.quantize(0.5), [2, -7]);
+        assert_eq!(Vec2::dequantize(a.quantize(0.5), 0.5), a.snap_to_grid(0.5));
+    }
+
+    #[test]
+    fn min_max() {
+        let a = Vec2::new(1.0, 5.0);
+        let b = Vec2::new(3.0, -2.0);
+
+        assert_eq!(a.min(b), Vec2::new(1.0, -2.0));
+        assert_eq!(a.max(b), Vec2::new(3.0, 5.0));
+        assert_eq!(
+            Vec2::new(-1.0, 7.0).clamp(Vec2::ZERO, Vec2::new(4.0, 4.0)),
+            Vec2::new(0.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn reflect() {
+        let v = Vec2::new(1.0, -1.0);
+        assert_eq!(v.reflect(Vec2::new(0.0, 1.0)), Vec2::new(1.0, 1.0));
+        assert_eq!(v.reflect(Vec2::new(-1.0, 0.0)), Vec2::new(-1.0, -1.0));
+    }
+
+    #[test]
+    fn try_normalize() {
+        assert_eq!(
+            Vec2::new(3.0, 4.0).try_normalize(),
+            Some(Vec2::new(0.6, 0.8))
+        );
+        assert_eq!(Vec2::ZERO.try_normalize(), None);
+        assert_eq!(Vec2::new(f32::NAN, 1.0).try_normalize(), None);
+        assert_eq!(Vec2::new(f32::INFINITY, 1.0).try_normalize(), None);
+
+        assert_eq!(Vec2::ZERO.normalize_or_zero(), Vec2::ZERO);
+        assert_eq!(
+            Vec2::ZERO.normalize_or(Vec2::new(0.0, 1.0)),
+            Vec2::new(0.0, 1.0)
+        );
+        assert_eq!(Vec2::new(0.0, 2.0).normalize_or_zero(), Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn perp() {
+        let a = Vec2::new(2.0, 1.0);
+        assert_eq!(a.perp(), Vec2::new(-1.0, 2.0));
+        assert_eq!(a.dot(a.perp()), 0.0);
+        assert_eq!(a.perp_dot(a.perp()), a.sqr_magnitude());
+        assert_eq!(Vec2::new(1.0, 0.0).perp_dot(Vec2::new(0.0, -1.0)), -1.0);
+        assert_eq!(a.perp_dot(a * 3.0), 0.0);
+        assert_eq!(Vec2::X.cross(Vec2::Y), 1.0);
+    }
+
+    #[test]
+    fn rotate() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        let a = Vec2::from_angle(FRAC_PI_2);
+        assert!((a - Vec2::new(0.0, 1.0)).magnitude() < 1e-6);
+        assert!((a.to_angle() - FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(Vec2::new(-1.0, 0.0).to_angle(), PI);
+
+        let v = Vec2::new(2.0, 1.0);
+        assert!((v.rotate(FRAC_PI_2) - v.perp()).magnitude() < 1e-6);
+        assert!((v.rotate(-PI) + v).magnitude() < 1e-6);
+        assert!((v.rotate(0.5).to_angle() - v.to_angle() - 0.5).abs() < 1e-6);
+        assert_eq!(v.rotate_by(Vec2::new(1.0, 0.0)), v);
+    }
+
+    #[test]
+    fn sum_product() {
+        let points = [
+            Vec2::new(1.0, 2.0),
+            Vec2::new(3.0, -4.0),
+            Vec2::new(2.0, 5.0),
+        ];
+        assert_eq!(points.iter().sum::<Vec2>(), Vec2::new(6.0, 3.0));
+        assert_eq!(points.into_iter().sum::<Vec2>() / 3.0, Vec2::new(2.0, 1.0));
+        assert_eq!(points.iter().product::<Vec2>(), Vec2::new(6.0, -40.0));
+        assert_eq!(std::iter::empty::<Vec2>().sum::<Vec2>(), Vec2::ZERO);
+        assert_eq!(std::iter::empty::<Vec2>().product::<Vec2>(), Vec2::ONE);
+    }
+
+    #[test]
+    fn signed_angle() {
+        use std::f32::consts::FRAC_PI_2;
+
+        let a = Vec2::new(2.0, 0.0);
+        assert!((a.signed_angle_to(Vec2::new(0.0, 3.0)) - FRAC_PI_2).abs() < 1e-6);
+        assert!((a.signed_angle_to(Vec2::new(0.0, -3.0)) + FRAC_PI_2).abs() < 1e-6);
+        assert!((a.angle_to(Vec2::new(0.0, -3.0)) - FRAC_PI_2).abs() < 1e-6);
+        assert!((a.signed_angle_to(a.rotate(-0.3)) + 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn wrap() {
+        let v = Vec2::new(-0.25, 1.5);
+        assert_eq!(v.wrap(Vec2::ZERO, Vec2::ONE), Vec2::new(0.75, 0.5));
+
+        let a = Vec2::new(3.0 * std::f32::consts::PI, 0.5).wrap_angle();
+        assert!((a - Vec2::new(-std::f32::consts::PI, 0.5)).magnitude() < 1e-5);
+    }
+
+    #[test]
+    fn polar() {
+        let v = Vec2::from_polar(2.0, std::f32::consts::FRAC_PI_2);
+        assert!((v - Vec2::new(0.0, 2.0)).magnitude() < 1e-6);
+
+        let (r, theta) = Vec2::new(-3.0, 3.0).to_polar();
+        assert!((Vec2::from_polar(r, theta) - Vec2::new(-3.0, 3.0)).magnitude() < 1e-5);
+    }
 }
@@ -2,7 +2,7 @@ use std::{fmt::Display, ops::Neg};
 
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
 
-use crate::Vec4;
+use crate::{Axis, Vec2, Vec4};
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -26,10 +26,69 @@ impl Vec3 {
     /// The one vector (1, 1, 1)
     pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
 
+    /// The unit vector along the positive x axis (1, 0, 0)
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+    /// The unit vector along the positive y axis (0, 1, 0)
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+    /// The unit vector along the positive z axis (0, 0, 1)
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+    /// The unit vector along the negative x axis (-1, 0, 0)
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0);
+    /// The unit vector along the negative y axis (0, -1, 0)
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0);
+    /// The unit vector along the negative z axis (0, 0, -1)
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0);
+
     pub const fn new(x: f32, y: f32, z: f32) -> Self {
         Self { x, y, z }
     }
 
+    /// Creates a vector with all components set to `v`
+    pub const fn splat(v: f32) -> Self {
+        Self::new(v, v, v)
+    }
+
+    /// Creates a vector from the spherical coordinates `r` (distance from the origin),
+    /// `theta` (polar angle in radians from the positive y axis)
+    /// and `phi` (azimuth in radians around the y axis, from the positive z axis towards the positive x axis).
+    ///
+    /// E.g. for an orbit camera, `theta` controls the pitch and `phi` the yaw.
+    pub fn from_spherical(r: f32, theta: f32, phi: f32) -> Self {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        Self::new(sin_theta * sin_phi, cos_theta, sin_theta * cos_phi) * r
+    }
+
+    /// Returns the spherical coordinates `(r, theta, phi)` of `self`,
+    /// see [`from_spherical()`](Self::from_spherical()).
+    ///
+    /// `theta` is in \[0; π\] and `phi` in \[-π; π\]. Returns zero angles for the zero vector.
+    pub fn to_spherical(&self) -> (f32, f32, f32) {
+        let r = self.magnitude();
+        if r == 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let theta = (self.y / r).clamp(-1.0, 1.0).acos();
+        let phi = self.x.atan2(self.z);
+        (r, theta, phi)
+    }
+
+    /// Returns the components of `self` as an array, in the order x, y, z
+    pub const fn to_array(&self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Returns the raw bit patterns of the components of `self`, see [`f32::to_bits()`]
+    pub fn to_bits(&self) -> [u32; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    /// Returns `true` if all components of `self` are neither infinite nor NaN
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
     /// Returns the square of the vector's length.
     ///
     /// Faster to compute than [`magnitude()`](Self::magnitude())
@@ -42,12 +101,18 @@ impl Vec3 {
         self.sqr_magnitude().sqrt()
     }
 
+    /// Returns the reciprocal of the vector's length, `1 / magnitude()`
+    pub fn magnitude_recip(&self) -> f32 {
+        self.magnitude().recip()
+    }
+
     /// Normalizes `self` in place
     pub fn normalize(&mut self) -> &mut Self {
         let m = self.magnitude();
         self.x /= m;
         self.y /= m;
         self.z /= m;
+        debug_validate!("Vec3::normalize", self, m);
         self
     }
     /// Returns a normalized copy of `self`
@@ -56,6 +121,262 @@ impl Vec3 {
         *self.clone().normalize()
     }
 
+    /// Returns a normalized copy of `self`, or `None` if `self` has zero, infinite or NaN length
+    #[must_use]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let r = self.magnitude_recip();
+        if r.is_finite() && r > 0.0 {
+            Some(*self * r)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a normalized copy of `self`, or `fallback` if `self` can not be normalized.
+    ///
+    /// See [`try_normalize()`](Self::try_normalize()).
+    #[must_use]
+    pub fn normalize_or(&self, fallback: Vec3) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a normalized copy of `self`, or zero if `self` can not be normalized.
+    ///
+    /// See [`try_normalize()`](Self::try_normalize()).
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    /// Linearly interpolates between `self` (`t = 0`) and `b` (`t = 1`),
+    /// with `t` clamped to \[0; 1\]
+    pub fn lerp(&self, b: Vec3, t: f32) -> Self {
+        self.lerp_unclamped(b, t.clamp(0.0, 1.0))
+    }
+
+    /// Linearly interpolates between `self` (`t = 0`) and `b` (`t = 1`).
+    ///
+    /// `t` is not clamped, so values outside of \[0; 1\] extrapolate.
+    pub fn lerp_unclamped(&self, b: Vec3, t: f32) -> Self {
+        *self + (b - *self) * t
+    }
+
+    /// Spherically interpolates between the directions `a` (`t = 0`) and `b` (`t = 1`),
+    /// rotating at constant angular speed along the shortest arc.
+    ///
+    /// `a` and `b` have to be normalized. Nearly parallel directions fall back to normalized linear interpolation,
+    /// opposite directions are rotated around an arbitrary perpendicular axis.
+    pub fn slerp(a: Vec3, b: Vec3, t: f32) -> Self {
+        let dot = a.dot(b).clamp(-1.0, 1.0);
+        if dot > 1.0 - 1e-6 {
+            return a.lerp_unclamped(b, t).normalized();
+        }
+
+        let (theta, perp) = if dot < -1.0 + 1e-6 {
+            let helper = if a.x.abs() < 0.9 { Vec3::X } else { Vec3::Y };
+            (std::f32::consts::PI, a.cross(helper).normalized())
+        } else {
+            (dot.acos(), (b - a * dot).normalized())
+        };
+
+        let (sin, cos) = (theta * t).sin_cos();
+        a * cos + perp * sin
+    }
+
+    /// Returns the point halfway between `a` and `b`
+    pub fn midpoint(a: Vec3, b: Vec3) -> Self {
+        (a + b) * 0.5
+    }
+
+    /// Reflects the incident vector `self` off a surface with the normal `normal`, like GLSL's `reflect()`.
+    ///
+    /// `self` points towards the surface and the result points away from it.
+    /// `normal` has to be normalized.
+    pub fn reflect(&self, normal: Vec3) -> Self {
+        *self - normal * (2.0 * normal.dot(*self))
+    }
+
+    /// Refracts the incident vector `self` at a surface with the normal `normal`, like GLSL's `refract()`.
+    ///
+    /// `eta` is the ratio of the indices of refraction (incident / transmitted).
+    /// `self` and `normal` have to be normalized.
+    /// Returns `None` in case of total internal reflection.
+    pub fn refract(&self, normal: Vec3, eta: f32) -> Option<Self> {
+        let d = normal.dot(*self);
+        let k = 1.0 - eta * eta * (1.0 - d * d);
+        if k < 0.0 {
+            None
+        } else {
+            Some(*self * eta - normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// Mirrors the point `self` about the plane through `plane_point` with the normal `plane_normal`.
+    ///
+    /// Unlike [`reflect()`](Self::reflect()), this takes the plane's position into account,
+    /// matching [`Mat4::reflect()`](crate::Mat4::reflect()). `plane_normal` does not need to be normalized.
+    pub fn mirrored(&self, plane_point: Vec3, plane_normal: Vec3) -> Self {
+        let n = plane_normal.normalized();
+        *self - n * (2.0 * (*self - plane_point).dot(n))
+    }
+
+    /// Returns the projection of `self` onto `other`, i.e. the component of `self` parallel to `other`
+    pub fn project_onto(&self, other: Vec3) -> Self {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Like [`project_onto()`](Self::project_onto()), but `other` has to be normalized
+    pub fn project_onto_normalized(&self, other: Vec3) -> Self {
+        other * self.dot(other)
+    }
+
+    /// Returns the rejection of `self` from `other`, i.e. the component of `self` perpendicular to `other`
+    pub fn reject_from(&self, other: Vec3) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    /// Returns the componentwise absolute value of `self`
+    pub fn abs(&self) -> Self {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// Returns the componentwise sign of `self`, see [`f32::signum()`]
+    pub fn signum(&self) -> Self {
+        Vec3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// Returns the componentwise floor of `self`, i.e. the largest integers less than or equal to the components
+    pub fn floor(&self) -> Self {
+        Vec3::new(self.x.floor(), self.y.floor(), self.z.floor())
+    }
+
+    /// Returns the componentwise ceiling of `self`, i.e. the smallest integers greater than or equal to the components
+    pub fn ceil(&self) -> Self {
+        Vec3::new(self.x.ceil(), self.y.ceil(), self.z.ceil())
+    }
+
+    /// Returns `self` with each component rounded to the nearest integer, half-way cases away from zero
+    pub fn round(&self) -> Self {
+        Vec3::new(self.x.round(), self.y.round(), self.z.round())
+    }
+
+    /// Returns the fractional part of each component, i.e. `self - self.floor()` like GLSL's `fract()`.
+    ///
+    /// Unlike [`f32::fract()`], the result is never negative.
+    pub fn fract(&self) -> Self {
+        *self - self.floor()
+    }
+
+    /// Returns `self` with every component raised to the power of `n`, see [`f32::powf()`]
+    pub fn powf(&self, n: f32) -> Self {
+        Vec3::new(self.x.powf(n), self.y.powf(n), self.z.powf(n))
+    }
+
+    /// Returns the componentwise square root of `self`
+    pub fn sqrt(&self) -> Self {
+        Vec3::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt())
+    }
+
+    /// Returns the componentwise exponential `e^self`
+    pub fn exp(&self) -> Self {
+        Vec3::new(self.x.exp(), self.y.exp(), self.z.exp())
+    }
+
+    /// Returns the componentwise natural logarithm of `self`
+    pub fn ln(&self) -> Self {
+        Vec3::new(self.x.ln(), self.y.ln(), self.z.ln())
+    }
+
+    /// Returns the componentwise reciprocal `1 / self`
+    pub fn recip(&self) -> Self {
+        Vec3::new(self.x.recip(), self.y.recip(), self.z.recip())
+    }
+
+    /// Returns the componentwise minimum of `self` and `b`
+    pub fn min(&self, b: Vec3) -> Self {
+        Vec3::new(self.x.min(b.x), self.y.min(b.y), self.z.min(b.z))
+    }
+
+    /// Returns the componentwise maximum of `self` and `b`
+    pub fn max(&self, b: Vec3) -> Self {
+        Vec3::new(self.x.max(b.x), self.y.max(b.y), self.z.max(b.z))
+    }
+
+    /// Clamps each component of `self` to the range given by the corresponding components of `min` and `max`
+    pub fn clamp(&self, min: Vec3, max: Vec3) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Wraps each component of `self` into the range given by the corresponding components of `min` and `max`,
+    /// see [`wrap()`](crate::wrap())
+    pub fn wrap(&self, min: Vec3, max: Vec3) -> Self {
+        Vec3::new(
+            crate::wrap(self.x, min.x, max.x),
+            crate::wrap(self.y, min.y, max.y),
+            crate::wrap(self.z, min.z, max.z),
+        )
+    }
+
+    /// Wraps each component of `self` into the range \[-π; π), see [`wrap_angle()`](crate::wrap_angle())
+    pub fn wrap_angle(&self) -> Self {
+        Vec3::new(
+            crate::wrap_angle(self.x),
+            crate::wrap_angle(self.y),
+            crate::wrap_angle(self.z),
+        )
+    }
+
+    /// Returns the smallest component of `self`
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Returns the largest component of `self`
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Returns the sum of all components of `self`
+    pub fn element_sum(&self) -> f32 {
+        self.x + self.y + self.z
+    }
+
+    /// Returns the product of all components of `self`
+    pub fn element_product(&self) -> f32 {
+        self.x * self.y * self.z
+    }
+
+    /// Returns an iterator over the components of `self`, in the order x, y, z
+    pub fn iter(&self) -> std::array::IntoIter<f32, 3> {
+        self.to_array().into_iter()
+    }
+
+    /// Returns the axis of the largest component of `self`.
+    ///
+    /// Ties are resolved in the order x, y, z.
+    pub fn max_axis(&self) -> Axis {
+        if self.x >= self.y && self.x >= self.z {
+            Axis::PosX
+        } else if self.y >= self.z {
+            Axis::PosY
+        } else {
+            Axis::PosZ
+        }
+    }
+
+    /// Returns the signed axis of the component of `self` with the largest absolute value,
+    /// e.g. [`Axis::NegY`] for (1, -3, 2).
+    ///
+    /// Useful for picking cubemap faces or projection planes. Ties are resolved in the order x, y, z.
+    pub fn abs_max_axis(&self) -> Axis {
+        match self.abs().max_axis() {
+            Axis::PosX if self.x < 0.0 => Axis::NegX,
+            Axis::PosY if self.y < 0.0 => Axis::NegY,
+            Axis::PosZ if self.z < 0.0 => Axis::NegZ,
+            axis => axis,
+        }
+    }
+
     /// Returns the dot product of `self` and `b`
     pub fn dot(&self, b: Vec3) -> f32 {
         self.x * b.x + self.y * b.y + self.z * b.z
@@ -71,6 +392,39 @@ impl Vec3 {
         }
     }
 
+    /// Returns the unsigned angle between `self` and `other` in radians, in \[0; π\]
+    pub fn angle_to(&self, other: Vec3) -> f32 {
+        self.cross(other).magnitude().atan2(self.dot(other))
+    }
+
+    /// Returns the angle between `self` and `other` in radians, in \[-π; π\].
+    ///
+    /// The angle is positive if rotating `self` towards `other` is a counter-clockwise rotation
+    /// around `axis` (using the right hand rule), e.g. when yawing around the up axis.
+    pub fn signed_angle_to(&self, other: Vec3, axis: Vec3) -> f32 {
+        let angle = self.angle_to(other);
+        if axis.dot(self.cross(other)) < 0.0 {
+            -angle
+        } else {
+            angle
+        }
+    }
+
+    /// Returns the scalar triple product `a · (b × c)`.
+    ///
+    /// This is the signed volume of the parallelepiped spanned by `a`, `b` and `c`,
+    /// positive if they form a right-handed system and zero if they are coplanar.
+    pub fn triple(a: Vec3, b: Vec3, c: Vec3) -> f32 {
+        a.dot(b.cross(c))
+    }
+
+    /// Returns the x and y components of `self`, dropping `z`.
+    ///
+    /// The inverse of [`Vec2::extend()`].
+    pub const fn truncate(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
     pub fn extend(&self, w: f32) -> Vec4 {
         Vec4 {
             x: self.x,
@@ -79,6 +433,43 @@ impl Vec3 {
             w,
         }
     }
+
+    /// Creates a vector from the homogeneous coordinates `v` by dividing x, y and z by `w`.
+    ///
+    /// Same as [`Vec4::to_vec3_homogeneous()`].
+    pub fn from_homogeneous(v: Vec4) -> Self {
+        v.to_vec3_homogeneous()
+    }
+
+    /// Returns `self` with every component rounded to the nearest multiple of `step`
+    #[must_use]
+    pub fn snap_to_grid(&self, step: f32) -> Self {
+        Self {
+            x: (self.x / step).round() * step,
+            y: (self.y / step).round() * step,
+            z: (self.z / step).round() * step,
+        }
+    }
+
+    /// Quantizes `self` to integer multiples of `step`.
+    ///
+    /// The original vector can be approximately restored with [`dequantize()`](Self::dequantize())
+    pub fn quantize(&self, step: f32) -> [i32; 3] {
+        [
+            (self.x / step).round() as i32,
+            (self.y / step).round() as i32,
+            (self.z / step).round() as i32,
+        ]
+    }
+
+    /// Restores a vector that was quantized by [`quantize()`](Self::quantize()) with the same `step`
+    pub fn dequantize(q: [i32; 3], step: f32) -> Self {
+        Self {
+            x: q[0] as f32 * step,
+            y: q[1] as f32 * step,
+            z: q[2] as f32 * step,
+        }
+    }
 }
 
 /// Vec3 swizzles
@@ -204,13 +595,16 @@ impl Vec3 {
     swizzle!(z, z, z, z);
 }
 
+byte_conversions!(Vec3, x, y, z);
+norm_conversions!(Vec3, x, y, z);
+
 impl_op_ex!(+= |a: &mut Vec3, b: &Vec3| { a.x += b.x; a.y += b.y; a.z += b.z; });
 impl_op_ex!(-= |a: &mut Vec3, b: &Vec3| { a.x -= b.x; a.y -= b.y; a.z -= b.z; });
 impl_op_ex!(*= |a: &mut Vec3, b: &Vec3| { a.x *= b.x; a.y *= b.y; a.z *= b.z; });
-impl_op_ex!(/= |a: &mut Vec3, b: &Vec3| { a.x /= b.x; a.y /= b.y; a.z /= b.z; });
+impl_op_ex!(/= |a: &mut Vec3, b: &Vec3| { a.x /= b.x; a.y /= b.y; a.z /= b.z; debug_validate!("Vec3 /= Vec3", a, b); });
 
 impl_op_ex!(*= |a: &mut Vec3, b: &f32| { a.x *= b; a.y *= b; a.z *= b; });
-impl_op_ex!(/= |a: &mut Vec3, b: &f32| { a.x /= b; a.y /= b; a.z /= b; });
+impl_op_ex!(/= |a: &mut Vec3, b: &f32| { a.x /= b; a.y /= b; a.z /= b; debug_validate!("Vec3 /= f32", a, b); });
 
 impl_op_ex!(+ |a: &Vec3, b: &Vec3| -> Vec3 { Vec3{x: a.x + b.x, y: a.y + b.y, z: a.z + b.z } });
 impl_op_ex!(-|a: &Vec3, b: &Vec3| -> Vec3 {
@@ -227,7 +621,7 @@ impl_op_ex!(*|a: &Vec3, b: &Vec3| -> Vec3 {
         z: a.z * b.z,
     }
 });
-impl_op_ex!(/ |a: &Vec3, b: &Vec3| -> Vec3 { Vec3{x: a.x / b.x, y: a.y / b.y, z: a.z / b.z } });
+impl_op_ex!(/ |a: &Vec3, b: &Vec3| -> Vec3 { let res = Vec3{x: a.x / b.x, y: a.y / b.y, z: a.z / b.z }; debug_validate!("Vec3 / Vec3", res, a, b); res });
 
 impl_op_ex_commutative!(*|a: &Vec3, b: &f32| -> Vec3 {
     Vec3 {
@@ -236,8 +630,8 @@ impl_op_ex_commutative!(*|a: &Vec3, b: &f32| -> Vec3 {
         z: a.z * b,
     }
 });
-impl_op_ex!(/ |a: &Vec3, b: &f32| -> Vec3 { Vec3{x: a.x / b, y: a.y / b, z: a.z / b } });
-impl_op_ex!(/ |a: &f32, b: &Vec3| -> Vec3 { Vec3{x: a / b.x, y: a / b.y, z: a / b.z } });
+impl_op_ex!(/ |a: &Vec3, b: &f32| -> Vec3 { let res = Vec3{x: a.x / b, y: a.y / b, z: a.z / b }; debug_validate!("Vec3 / f32", res, a, b); res });
+impl_op_ex!(/ |a: &f32, b: &Vec3| -> Vec3 { let res = Vec3{x: a / b.x, y: a / b.y, z: a / b.z }; debug_validate!("f32 / Vec3", res, a, b); res });
 
 impl Neg for Vec3 {
     type Output = Vec3;
@@ -260,6 +654,68 @@ impl From<[f32; 3]> for Vec3 {
     }
 }
 
+impl From<Vec3> for [f32; 3] {
+    fn from(v: Vec3) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(f32, f32, f32)> for Vec3 {
+    fn from((x, y, z): (f32, f32, f32)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Vec3> for (f32, f32, f32) {
+    fn from(v: Vec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl std::iter::Sum<Vec3> for Vec3 {
+    fn sum<I: Iterator<Item = Vec3>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Vec3> for Vec3 {
+    fn sum<I: Iterator<Item = &'a Vec3>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+/// Multiplies componentwise
+impl std::iter::Product<Vec3> for Vec3 {
+    fn product<I: Iterator<Item = Vec3>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+/// Multiplies componentwise
+impl<'a> std::iter::Product<&'a Vec3> for Vec3 {
+    fn product<I: Iterator<Item = &'a Vec3>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl IntoIterator for Vec3 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Vec3 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -381,4 +837,199 @@ mod tests {
         c /= 2.0;
         assert_eq!(c, a / 2.0);
     }
+
+    #[test]
+    fn snapping() {
+        let a = Vec3::new(1.2, -3.7, 0.3);
+
+        assert_eq!(a.snap_to_grid(0.5), Vec3::new(1.0, -3.5, 0.5));
+        assert_eq!(a.quantize(0.5), [2, -7, 1]);
+        assert_eq!(Vec3::dequantize(a.quantize(0.5), 0.5), a.snap_to_grid(0.5));
+    }
+
+    #[test]
+    fn bytes() {
+        let a = Vec3::new(1.0, -2.5, 3.0);
+
+        assert_eq!(Vec3::from_le_bytes(a.to_le_bytes()), a);
+        assert_eq!(Vec3::from_be_bytes(a.to_be_bytes()), a);
+        assert_eq!(a.to_le_bytes()[4..8], (-2.5f32).to_le_bytes());
+        assert_eq!(a.to_be_bytes()[8..12], 3.0f32.to_be_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "debug-validate")]
+    #[should_panic(expected = "Vec3::normalize produced a non-finite value")]
+    fn validate() {
+        let _ = Vec3::ZERO.normalized();
+    }
+
+    #[test]
+    fn lerp() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(3.0, -2.0, 5.0);
+
+        assert_eq!(a.lerp(b, 0.5), Vec3::new(2.0, 0.0, 4.0));
+        assert_eq!(a.lerp(b, 2.0), b);
+        assert_eq!(a.lerp(b, -1.0), a);
+        assert_eq!(a.lerp_unclamped(b, 2.0), Vec3::new(5.0, -6.0, 7.0));
+        assert_eq!(a.lerp(b, 0.25), crate::Lerp::lerp(&a, b, 0.25));
+    }
+
+    #[test]
+    fn reflect() {
+        let n = Vec3::new(1.0, 1.0, 0.0).normalized();
+        let r = Vec3::new(-1.0, 0.0, 0.0).reflect(n);
+        assert!((r - Vec3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn refract() {
+        let n = Vec3::new(0.0, 1.0, 0.0);
+        let i = Vec3::new(1.0, -1.0, 0.0).normalized();
+        assert_eq!(i.refract(n, 1.0), Some(i));
+
+        // entering a denser medium bends the ray towards the normal
+        let r = i.refract(n, 1.0 / 1.5).unwrap();
+        assert!((r.magnitude() - 1.0).abs() < 1e-6);
+        assert!(r.x > 0.0 && r.x < i.x && r.y < 0.0);
+        assert!((r.x - i.x / 1.5).abs() < 1e-6);
+
+        // leaving it at a shallow angle is reflected entirely
+        assert_eq!(i.refract(n, 1.5), None);
+    }
+
+    #[test]
+    fn project_reject() {
+        let v = Vec3::new(3.0, -2.0, 1.0);
+        let n = Vec3::new(0.0, 2.0, 0.0);
+        assert_eq!(v.project_onto(n), Vec3::new(0.0, -2.0, 0.0));
+        assert_eq!(v.reject_from(n), Vec3::new(3.0, 0.0, 1.0));
+        assert_eq!(v.project_onto_normalized(n.normalized()), v.project_onto(n));
+        assert_eq!(v.project_onto(n) + v.reject_from(n), v);
+    }
+
+    #[test]
+    fn truncate() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.truncate(), Vec2::new(1.0, 2.0));
+        assert_eq!(v.truncate().extend(v.z), v);
+        assert_eq!(v.extend(4.0).truncate(), v);
+    }
+
+    #[test]
+    fn axis_constants() {
+        assert_eq!(Vec3::X.cross(Vec3::Y), Vec3::Z);
+        assert_eq!(Vec3::Y.cross(Vec3::Z), Vec3::X);
+        assert_eq!(-Vec3::Z, Vec3::NEG_Z);
+        assert_eq!(Vec3::X + Vec3::Y + Vec3::Z, Vec3::ONE);
+    }
+
+    #[test]
+    fn iter() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.iter().collect::<Vec<_>>(), [1.0, 2.0, 3.0]);
+        assert_eq!(v.into_iter().map(|c| c * c).sum::<f32>(), v.sqr_magnitude());
+
+        let mut buf = Vec::new();
+        for c in &v {
+            buf.push(c);
+        }
+        buf.extend(Vec3::ONE);
+        assert_eq!(buf, [1.0, 2.0, 3.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let v: Vec3 = (1.0, 2.0, 3.0).into();
+        assert_eq!(v, Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(<(f32, f32, f32)>::from(v), (1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn triple() {
+        assert_eq!(Vec3::triple(Vec3::X, Vec3::Y, Vec3::Z), 1.0);
+        assert_eq!(Vec3::triple(Vec3::Y, Vec3::X, Vec3::Z), -1.0);
+        assert_eq!(Vec3::triple(Vec3::X * 2.0, Vec3::Y * 3.0, Vec3::Z), 6.0);
+        assert_eq!(Vec3::triple(Vec3::X, Vec3::Y, Vec3::X + Vec3::Y), 0.0);
+    }
+
+    #[test]
+    fn powf() {
+        let v = Vec3::new(1.0, 4.0, 9.0);
+        assert_eq!(v.sqrt(), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(v.sqrt().powf(2.0), v);
+        assert_eq!(Vec3::ZERO.exp(), Vec3::ONE);
+        assert!((v.ln().exp() - v).magnitude() < 1e-5);
+    }
+
+    #[test]
+    fn slerp() {
+        let a = Vec3::X;
+        let b = Vec3::Y;
+        assert!((Vec3::slerp(a, b, 0.0) - a).magnitude() < 1e-6);
+        assert!((Vec3::slerp(a, b, 1.0) - b).magnitude() < 1e-6);
+
+        let half = Vec3::slerp(a, b, 0.5);
+        assert!((half - Vec3::new(1.0, 1.0, 0.0).normalized()).magnitude() < 1e-6);
+        let third = Vec3::slerp(a, b, 1.0 / 3.0);
+        assert!((third.dot(a) - (std::f32::consts::PI / 6.0).cos()).abs() < 1e-6);
+
+        assert_eq!(Vec3::slerp(a, a, 0.5), a);
+
+        let opposite = Vec3::slerp(Vec3::Z, Vec3::NEG_Z, 0.5);
+        assert!((opposite.magnitude() - 1.0).abs() < 1e-6);
+        assert!(opposite.z.abs() < 1e-6);
+        assert!((Vec3::slerp(Vec3::Z, Vec3::NEG_Z, 1.0) - Vec3::NEG_Z).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn max_axis() {
+        assert_eq!(Vec3::new(1.0, -3.0, 2.0).max_axis(), Axis::PosZ);
+        assert_eq!(Vec3::new(1.0, -3.0, 2.0).abs_max_axis(), Axis::NegY);
+        assert_eq!(Vec3::new(-0.5, 0.2, 0.1).abs_max_axis(), Axis::NegX);
+        assert_eq!(Vec3::new(0.0, 0.0, 0.1).abs_max_axis(), Axis::PosZ);
+        assert_eq!(Vec3::ONE.max_axis(), Axis::PosX);
+    }
+
+    #[test]
+    fn signed_angle() {
+        use std::f32::consts::FRAC_PI_2;
+
+        assert!((Vec3::X.angle_to(Vec3::Y * 2.0) - FRAC_PI_2).abs() < 1e-6);
+        assert!((Vec3::X.signed_angle_to(Vec3::Y, Vec3::Z) - FRAC_PI_2).abs() < 1e-6);
+        assert!((Vec3::X.signed_angle_to(Vec3::Y, Vec3::NEG_Z) + FRAC_PI_2).abs() < 1e-6);
+
+        let a = Vec3::new(1.0, 0.0, 1.0);
+        assert!((a.signed_angle_to(Vec3::new(1.0, 0.0, -1.0), Vec3::Y) - FRAC_PI_2).abs() < 1e-6);
+        assert!(Vec3::X.angle_to(Vec3::X).abs() < 1e-6);
+    }
+
+    #[test]
+    fn spherical() {
+        use std::f32::consts::FRAC_PI_2;
+
+        assert!((Vec3::from_spherical(2.0, 0.0, 1.0) - Vec3::Y * 2.0).magnitude() < 1e-6);
+        assert!((Vec3::from_spherical(1.0, FRAC_PI_2, 0.0) - Vec3::Z).magnitude() < 1e-6);
+        assert!((Vec3::from_spherical(1.0, FRAC_PI_2, FRAC_PI_2) - Vec3::X).magnitude() < 1e-6);
+
+        let v = Vec3::new(1.0, -2.0, -3.0);
+        let (r, theta, phi) = v.to_spherical();
+        assert!((Vec3::from_spherical(r, theta, phi) - v).magnitude() < 1e-5);
+        assert_eq!(Vec3::ZERO.to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn mirrored() {
+        let p = Vec3::new(1.0, 5.0, -2.0);
+        assert_eq!(
+            p.mirrored(Vec3::new(0.0, 2.0, 0.0), Vec3::Y * 3.0),
+            Vec3::new(1.0, -1.0, -2.0)
+        );
+
+        let (point, normal) = (Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, -1.0, 2.0));
+        let m = crate::Mat4::reflect(point, normal) * p.extend(1.0);
+        assert!((p.mirrored(point, normal) - m.truncate()).magnitude() < 1e-5);
+        assert!((p.mirrored(point, normal).mirrored(point, normal) - p).magnitude() < 1e-5);
+    }
 }
@@ -2,6 +2,8 @@ use std::{fmt::Display, ops::Neg};
 
 use auto_ops::{impl_op_ex, impl_op_ex_commutative};
 
+use crate::Vec3;
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -25,10 +27,52 @@ impl Vec4 {
     /// The one vector (1, 1, 1)
     pub const ONE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
 
+    /// The unit vector along the positive x axis (1, 0, 0, 0)
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+    /// The unit vector along the positive y axis (0, 1, 0, 0)
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+    /// The unit vector along the positive z axis (0, 0, 1, 0)
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+    /// The unit vector along the positive w axis (0, 0, 0, 1)
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    /// The unit vector along the negative x axis (-1, 0, 0, 0)
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0, 0.0);
+    /// The unit vector along the negative y axis (0, -1, 0, 0)
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0, 0.0);
+    /// The unit vector along the negative z axis (0, 0, -1, 0)
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0, 0.0);
+    /// The unit vector along the negative w axis (0, 0, 0, -1)
+    pub const NEG_W: Self = Self::new(0.0, 0.0, 0.0, -1.0);
+
     pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self { x, y, z, w }
     }
 
+    /// Creates a vector with all components set to `v`
+    pub const fn splat(v: f32) -> Self {
+        Self::new(v, v, v, v)
+    }
+
+    /// Returns the components of `self` as an array, in the order x, y, z, w
+    pub const fn to_array(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Returns the raw bit patterns of the components of `self`, see [`f32::to_bits()`]
+    pub fn to_bits(&self) -> [u32; 4] {
+        [
+            self.x.to_bits(),
+            self.y.to_bits(),
+            self.z.to_bits(),
+            self.w.to_bits(),
+        ]
+    }
+
+    /// Returns `true` if all components of `self` are neither infinite nor NaN
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
     /// Returns the square of the vector's length.
     ///
     /// Faster to compute than [`magnitude()`](Self::magnitude())
@@ -41,6 +85,11 @@ impl Vec4 {
         self.sqr_magnitude().sqrt()
     }
 
+    /// Returns the reciprocal of the vector's length, `1 / magnitude()`
+    pub fn magnitude_recip(&self) -> f32 {
+        self.magnitude().recip()
+    }
+
     /// Normalizes `self` in place
     pub fn normalize(&mut self) -> &mut Self {
         let m = self.magnitude();
@@ -48,6 +97,7 @@ impl Vec4 {
         self.y /= m;
         self.z /= m;
         self.w /= m;
+        debug_validate!("Vec4::normalize", self, m);
         self
     }
     /// Returns a normalized copy of `self`
@@ -56,10 +106,221 @@ impl Vec4 {
         *self.clone().normalize()
     }
 
+    /// Returns a normalized copy of `self`, or `None` if `self` has zero, infinite or NaN length
+    #[must_use]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let r = self.magnitude_recip();
+        if r.is_finite() && r > 0.0 {
+            Some(*self * r)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a normalized copy of `self`, or `fallback` if `self` can not be normalized.
+    ///
+    /// See [`try_normalize()`](Self::try_normalize()).
+    #[must_use]
+    pub fn normalize_or(&self, fallback: Vec4) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns a normalized copy of `self`, or zero if `self` can not be normalized.
+    ///
+    /// See [`try_normalize()`](Self::try_normalize()).
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    /// Linearly interpolates between `self` (`t = 0`) and `b` (`t = 1`),
+    /// with `t` clamped to \[0; 1\]
+    pub fn lerp(&self, b: Vec4, t: f32) -> Self {
+        self.lerp_unclamped(b, t.clamp(0.0, 1.0))
+    }
+
+    /// Linearly interpolates between `self` (`t = 0`) and `b` (`t = 1`).
+    ///
+    /// `t` is not clamped, so values outside of \[0; 1\] extrapolate.
+    pub fn lerp_unclamped(&self, b: Vec4, t: f32) -> Self {
+        *self + (b - *self) * t
+    }
+
+    /// Returns the point halfway between `a` and `b`
+    pub fn midpoint(a: Vec4, b: Vec4) -> Self {
+        (a + b) * 0.5
+    }
+
+    /// Returns the componentwise absolute value of `self`
+    pub fn abs(&self) -> Self {
+        Vec4::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+    }
+
+    /// Returns the componentwise sign of `self`, see [`f32::signum()`]
+    pub fn signum(&self) -> Self {
+        Vec4::new(
+            self.x.signum(),
+            self.y.signum(),
+            self.z.signum(),
+            self.w.signum(),
+        )
+    }
+
+    /// Returns the componentwise floor of `self`, i.e. the largest integers less than or equal to the components
+    pub fn floor(&self) -> Self {
+        Vec4::new(
+            self.x.floor(),
+            self.y.floor(),
+            self.z.floor(),
+            self.w.floor(),
+        )
+    }
+
+    /// Returns the componentwise ceiling of `self`, i.e. the smallest integers greater than or equal to the components
+    pub fn ceil(&self) -> Self {
+        Vec4::new(self.x.ceil(), self.y.ceil(), self.z.ceil(), self.w.ceil())
+    }
+
+    /// Returns `self` with each component rounded to the nearest integer, half-way cases away from zero
+    pub fn round(&self) -> Self {
+        Vec4::new(
+            self.x.round(),
+            self.y.round(),
+            self.z.round(),
+            self.w.round(),
+        )
+    }
+
+    /// Returns the fractional part of each component, i.e. `self - self.floor()` like GLSL's `fract()`.
+    ///
+    /// Unlike [`f32::fract()`], the result is never negative.
+    pub fn fract(&self) -> Self {
+        *self - self.floor()
+    }
+
+    /// Returns `self` with every component raised to the power of `n`, see [`f32::powf()`]
+    pub fn powf(&self, n: f32) -> Self {
+        Vec4::new(
+            self.x.powf(n),
+            self.y.powf(n),
+            self.z.powf(n),
+            self.w.powf(n),
+        )
+    }
+
+    /// Returns the componentwise square root of `self`
+    pub fn sqrt(&self) -> Self {
+        Vec4::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt(), self.w.sqrt())
+    }
+
+    /// Returns the componentwise exponential `e^self`
+    pub fn exp(&self) -> Self {
+        Vec4::new(self.x.exp(), self.y.exp(), self.z.exp(), self.w.exp())
+    }
+
+    /// Returns the componentwise natural logarithm of `self`
+    pub fn ln(&self) -> Self {
+        Vec4::new(self.x.ln(), self.y.ln(), self.z.ln(), self.w.ln())
+    }
+
+    /// Returns the componentwise reciprocal `1 / self`
+    pub fn recip(&self) -> Self {
+        Vec4::new(
+            self.x.recip(),
+            self.y.recip(),
+            self.z.recip(),
+            self.w.recip(),
+        )
+    }
+
+    /// Returns the componentwise minimum of `self` and `b`
+    pub fn min(&self, b: Vec4) -> Self {
+        Vec4::new(
+            self.x.min(b.x),
+            self.y.min(b.y),
+            self.z.min(b.z),
+            self.w.min(b.w),
+        )
+    }
+
+    /// Returns the componentwise maximum of `self` and `b`
+    pub fn max(&self, b: Vec4) -> Self {
+        Vec4::new(
+            self.x.max(b.x),
+            self.y.max(b.y),
+            self.z.max(b.z),
+            self.w.max(b.w),
+        )
+    }
+
+    /// Clamps each component of `self` to the range given by the corresponding components of `min` and `max`
+    pub fn clamp(&self, min: Vec4, max: Vec4) -> Self {
+        self.max(min).min(max)
+    }
+
+    /// Wraps each component of `self` into the range given by the corresponding components of `min` and `max`,
+    /// see [`wrap()`](crate::wrap())
+    pub fn wrap(&self, min: Vec4, max: Vec4) -> Self {
+        Vec4::new(
+            crate::wrap(self.x, min.x, max.x),
+            crate::wrap(self.y, min.y, max.y),
+            crate::wrap(self.z, min.z, max.z),
+            crate::wrap(self.w, min.w, max.w),
+        )
+    }
+
+    /// Wraps each component of `self` into the range \[-π; π), see [`wrap_angle()`](crate::wrap_angle())
+    pub fn wrap_angle(&self) -> Self {
+        Vec4::new(
+            crate::wrap_angle(self.x),
+            crate::wrap_angle(self.y),
+            crate::wrap_angle(self.z),
+            crate::wrap_angle(self.w),
+        )
+    }
+
+    /// Returns the smallest component of `self`
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    /// Returns the largest component of `self`
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
+
+    /// Returns the sum of all components of `self`
+    pub fn element_sum(&self) -> f32 {
+        self.x + self.y + self.z + self.w
+    }
+
+    /// Returns the product of all components of `self`
+    pub fn element_product(&self) -> f32 {
+        self.x * self.y * self.z * self.w
+    }
+
+    /// Returns an iterator over the components of `self`, in the order x, y, z, w
+    pub fn iter(&self) -> std::array::IntoIter<f32, 4> {
+        self.to_array().into_iter()
+    }
+
     /// Returns the dot product of `self` and `b`
     pub fn dot(&self, b: Vec4) -> f32 {
         self.x * b.x + self.y * b.y + self.z * b.z + self.w * b.w
     }
+
+    /// Returns the x, y and z components of `self`, dropping `w`.
+    ///
+    /// The inverse of [`Vec3::extend()`].
+    pub const fn truncate(&self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the x, y and z components of `self` divided by `w`,
+    /// e.g. to get from clip space to normalized device coordinates
+    pub fn to_vec3_homogeneous(&self) -> Vec3 {
+        self.truncate() / self.w
+    }
 }
 
 /// Vec4 swizzles
@@ -404,13 +665,16 @@ impl Vec4 {
     swizzle!(w, w, w, w);
 }
 
+byte_conversions!(Vec4, x, y, z, w);
+norm_conversions!(Vec4, x, y, z, w);
+
 impl_op_ex!(+= |a: &mut Vec4, b: &Vec4| { a.x += b.x; a.y += b.y; a.z += b.z; a.w += b.w; });
 impl_op_ex!(-= |a: &mut Vec4, b: &Vec4| { a.x -= b.x; a.y -= b.y; a.z -= b.z; a.w -= b.w; });
 impl_op_ex!(*= |a: &mut Vec4, b: &Vec4| { a.x *= b.x; a.y *= b.y; a.z *= b.z; a.w *= b.w; });
-impl_op_ex!(/= |a: &mut Vec4, b: &Vec4| { a.x /= b.x; a.y /= b.y; a.z /= b.z; a.w /= b.w; });
+impl_op_ex!(/= |a: &mut Vec4, b: &Vec4| { a.x /= b.x; a.y /= b.y; a.z /= b.z; a.w /= b.w; debug_validate!("Vec4 /= Vec4", a, b); });
 
 impl_op_ex!(*= |a: &mut Vec4, b: &f32| { a.x *= b; a.y *= b; a.z *= b; a.w *= b; });
-impl_op_ex!(/= |a: &mut Vec4, b: &f32| { a.x /= b; a.y /= b; a.z /= b; a.w /= b; });
+impl_op_ex!(/= |a: &mut Vec4, b: &f32| { a.x /= b; a.y /= b; a.z /= b; a.w /= b; debug_validate!("Vec4 /= f32", a, b); });
 
 impl_op_ex!(+ |a: &Vec4, b: &Vec4| -> Vec4 { Vec4{x: a.x + b.x, y: a.y + b.y, z: a.z + b.z, w: a.w + b.w } });
 impl_op_ex!(-|a: &Vec4, b: &Vec4| -> Vec4 {
@@ -429,7 +693,7 @@ impl_op_ex!(*|a: &Vec4, b: &Vec4| -> Vec4 {
         w: a.w * b.w,
     }
 });
-impl_op_ex!(/ |a: &Vec4, b: &Vec4| -> Vec4 { Vec4{x: a.x / b.x, y: a.y / b.y, z: a.z / b.z, w: a.w / b.w } });
+impl_op_ex!(/ |a: &Vec4, b: &Vec4| -> Vec4 { let res = Vec4{x: a.x / b.x, y: a.y / b.y, z: a.z / b.z, w: a.w / b.w }; debug_validate!("Vec4 / Vec4", res, a, b); res });
 
 impl_op_ex_commutative!(*|a: &Vec4, b: &f32| -> Vec4 {
     Vec4 {
@@ -439,8 +703,8 @@ impl_op_ex_commutative!(*|a: &Vec4, b: &f32| -> Vec4 {
         w: a.w * b,
     }
 });
-impl_op_ex!(/ |a: &Vec4, b: &f32| -> Vec4 { Vec4{x: a.x / b, y: a.y / b, z: a.z / b, w: a.w / b } });
-impl_op_ex!(/ |a: &f32, b: &Vec4| -> Vec4 { Vec4{x: a / b.x, y: a / b.y, z: a / b.z, w: a / b.w } });
+impl_op_ex!(/ |a: &Vec4, b: &f32| -> Vec4 { let res = Vec4{x: a.x / b, y: a.y / b, z: a.z / b, w: a.w / b }; debug_validate!("Vec4 / f32", res, a, b); res });
+impl_op_ex!(/ |a: &f32, b: &Vec4| -> Vec4 { let res = Vec4{x: a / b.x, y: a / b.y, z: a / b.z, w: a / b.w }; debug_validate!("f32 / Vec4", res, a, b); res });
 
 impl Neg for Vec4 {
     type Output = Vec4;
@@ -465,6 +729,68 @@ impl From<[f32; 4]> for Vec4 {
     }
 }
 
+impl From<Vec4> for [f32; 4] {
+    fn from(v: Vec4) -> Self {
+        v.to_array()
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Vec4 {
+    fn from((x, y, z, w): (f32, f32, f32, f32)) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Vec4> for (f32, f32, f32, f32) {
+    fn from(v: Vec4) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl std::iter::Sum<Vec4> for Vec4 {
+    fn sum<I: Iterator<Item = Vec4>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Vec4> for Vec4 {
+    fn sum<I: Iterator<Item = &'a Vec4>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+/// Multiplies componentwise
+impl std::iter::Product<Vec4> for Vec4 {
+    fn product<I: Iterator<Item = Vec4>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+/// Multiplies componentwise
+impl<'a> std::iter::Product<&'a Vec4> for Vec4 {
+    fn product<I: Iterator<Item = &'a Vec4>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl IntoIterator for Vec4 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Vec4 {
+    type Item = f32;
+    type IntoIter = std::array::IntoIter<f32, 4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -595,4 +921,77 @@ mod tests {
         c /= 2.0;
         assert_eq!(c, a / 2.0);
     }
+
+    #[test]
+    fn norm_conversions() {
+        let a = Vec4::new(-2.0, -1.0, 0.5, 1.0);
+
+        assert_eq!(a.to_unorm8(), [0, 0, 128, 255]);
+        assert_eq!(a.to_snorm8(), [-127, -127, 64, 127]);
+        assert_eq!(a.to_unorm16(), [0, 0, 32768, 65535]);
+        assert_eq!(a.to_snorm16(), [-32767, -32767, 16384, 32767]);
+
+        assert_eq!(
+            Vec4::from_unorm8([0, 255, 0, 255]),
+            Vec4::new(0.0, 1.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            Vec4::from_snorm8([-128, -127, 0, 127]),
+            Vec4::new(-1.0, -1.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            Vec4::from_snorm16([i16::MIN, 0, 0, i16::MAX]),
+            Vec4::new(-1.0, 0.0, 0.0, 1.0)
+        );
+
+        let b = Vec4::new(0.25, 0.5, 0.75, 1.0);
+        let decoded = Vec4::from_unorm16(b.to_unorm16());
+        assert!((decoded - b).magnitude() < 1.0 / u16::MAX as f32);
+    }
+
+    #[test]
+    fn rounding() {
+        let v = Vec4::new(-1.5, 0.25, 2.5, -0.75);
+
+        assert_eq!(v.abs(), Vec4::new(1.5, 0.25, 2.5, 0.75));
+        assert_eq!(v.signum(), Vec4::new(-1.0, 1.0, 1.0, -1.0));
+        assert_eq!(v.floor(), Vec4::new(-2.0, 0.0, 2.0, -1.0));
+        assert_eq!(v.ceil(), Vec4::new(-1.0, 1.0, 3.0, -0.0));
+        assert_eq!(v.round(), Vec4::new(-2.0, 0.0, 3.0, -1.0));
+        assert_eq!(v.fract(), Vec4::new(0.5, 0.25, 0.5, 0.25));
+    }
+
+    #[test]
+    fn element_reductions() {
+        let v = Vec4::new(2.0, -3.0, 0.5, 4.0);
+        assert_eq!(v.min_element(), -3.0);
+        assert_eq!(v.max_element(), 4.0);
+        assert_eq!(v.element_sum(), 3.5);
+        assert_eq!(v.element_product(), -12.0);
+    }
+
+    #[test]
+    fn splat() {
+        assert_eq!(Vec4::splat(2.5), Vec4::new(2.5, 2.5, 2.5, 2.5));
+        assert_eq!(Vec4::splat(1.0), Vec4::ONE);
+    }
+
+    #[test]
+    fn recip() {
+        let v = Vec4::new(2.0, -4.0, 0.5, 0.0);
+        assert_eq!(v.recip(), Vec4::new(0.5, -0.25, 2.0, f32::INFINITY));
+        assert_eq!(Vec4::new(0.0, 3.0, 0.0, 4.0).magnitude_recip(), 0.2);
+        assert_eq!(Vec4::ZERO.magnitude_recip(), f32::INFINITY);
+    }
+
+    #[test]
+    fn homogeneous() {
+        let v = Vec4::new(2.0, -4.0, 6.0, 2.0);
+        assert_eq!(v.to_vec3_homogeneous(), Vec3::new(1.0, -2.0, 3.0));
+        assert_eq!(Vec3::from_homogeneous(v), Vec3::new(1.0, -2.0, 3.0));
+        assert_eq!(
+            Vec3::new(1.0, 2.0, 3.0).extend(1.0).to_vec3_homogeneous(),
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+    }
 }